@@ -0,0 +1,296 @@
+use std::{borrow::Cow, time::SystemTime};
+
+use liquid_core::Runtime;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use time::OffsetDateTime;
+
+/// Options that apply to a single render of a Liquid JSON template.
+#[must_use]
+#[allow(missing_copy_implementations)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Environment {
+    seed: Option<u64>,
+    now: Option<SystemTime>,
+    delimiters: Option<Delimiters>,
+}
+
+impl Environment {
+    /// Create an environment with default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seed the random number generator used by filters like `uuid_v4` and `shuffle`, so the same
+    /// template, data, and seed always render the same output.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Fix the current time used by date filters like `to_iso8601` and `date` when given `now`.
+    pub fn with_now(mut self, now: SystemTime) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// Use alternate delimiters for the templates in JSON strings, e.g. to avoid clashing with
+    /// another templating layer that also uses `{{ }}`. Text that uses the standard delimiters is
+    /// then left as it is.
+    pub fn with_delimiters(mut self, delimiters: Delimiters) -> Self {
+        self.delimiters = (delimiters != Delimiters::default()).then_some(delimiters);
+        self
+    }
+
+    /// The delimiters of the templates in a render.
+    pub(crate) fn delimiters(&self) -> Delimiters {
+        self.delimiters.clone().unwrap_or_default()
+    }
+
+    /// Rewrite a template that uses the environment's delimiters to use the standard ones.
+    pub(crate) fn translate<'t>(&self, template: &'t str) -> Cow<'t, str> {
+        self.delimiters
+            .as_ref()
+            .map_or(Cow::Borrowed(template), |d| d.translate(template))
+    }
+
+    /// Make the environment available to filters for the duration of a render.
+    pub(crate) fn install(&self, runtime: &dyn Runtime) {
+        if let Some(seed) = self.seed {
+            runtime.registers().get_mut::<RandomRegister>().rng =
+                Some(ChaCha8Rng::seed_from_u64(seed));
+        }
+        if let Some(now) = self.now {
+            runtime.registers().get_mut::<ClockRegister>().now = Some(now.into());
+        }
+        if let Some(delimiters) = &self.delimiters {
+            runtime
+                .registers()
+                .get_mut::<DelimiterRegister>()
+                .delimiters = Some(delimiters.clone());
+        }
+    }
+}
+
+/// The random number generator shared by every filter in a render.
+#[derive(Default)]
+pub(crate) struct RandomRegister {
+    rng: Option<ChaCha8Rng>,
+}
+
+impl RandomRegister {
+    /// Get the render's random number generator, seeding it from entropy if the environment
+    /// didn't provide a seed.
+    pub(crate) fn rng(&mut self) -> &mut ChaCha8Rng {
+        self.rng.get_or_insert_with(ChaCha8Rng::from_entropy)
+    }
+}
+
+/// The current time shared by every filter in a render.
+#[derive(Default)]
+pub(crate) struct ClockRegister {
+    now: Option<OffsetDateTime>,
+}
+
+impl ClockRegister {
+    /// Get the render's current time, reading the system clock once per render if the environment
+    /// didn't fix it.
+    pub(crate) fn now(&mut self) -> OffsetDateTime {
+        *self.now.get_or_insert_with(OffsetDateTime::now_utc)
+    }
+}
+
+/// The delimiters that mark Liquid markup in a template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Delimiters {
+    output: (String, String),
+    tag: (String, String),
+}
+
+impl Default for Delimiters {
+    fn default() -> Self {
+        Self::new(("{{", "}}"), ("{%", "%}"))
+    }
+}
+
+impl Delimiters {
+    /// Create delimiters from the start and end of output markup (`{{ }}` by default) and of tag
+    /// markup (`{% %}` by default), e.g. `Delimiters::new(("[[", "]]"), ("[%", "%]"))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any delimiter is empty.
+    #[must_use]
+    pub fn new(output: (&str, &str), tag: (&str, &str)) -> Self {
+        assert!(
+            ![output.0, output.1, tag.0, tag.1].contains(&""),
+            "Delimiters can't be empty"
+        );
+        Self {
+            output: (output.0.to_owned(), output.1.to_owned()),
+            tag: (tag.0.to_owned(), tag.1.to_owned()),
+        }
+    }
+
+    /// The start and end of output markup.
+    pub(crate) fn output(&self) -> (&str, &str) {
+        (&self.output.0, &self.output.1)
+    }
+
+    /// The start and end of tag markup.
+    pub(crate) fn tag(&self) -> (&str, &str) {
+        (&self.tag.0, &self.tag.1)
+    }
+
+    /// Escape literal text so a template with these delimiters renders it as it is.
+    pub(crate) fn escape<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        if !text.contains(self.output.0.as_str()) && !text.contains(self.tag.0.as_str()) {
+            return Cow::Borrowed(text);
+        }
+        let mut output = String::with_capacity(text.len());
+        push_escaped(&mut output, text, self.output(), &self.tag.0);
+        Cow::Owned(output)
+    }
+
+    /// Rewrite a template that uses these delimiters into one that uses the standard ones,
+    /// escaping any text that looks like standard markup so it's left as it is.
+    pub(crate) fn translate<'t>(&self, template: &'t str) -> Cow<'t, str> {
+        let markers = [&self.output.0, &self.tag.0];
+        if !markers.iter().any(|m| template.contains(m.as_str()))
+            && !template.contains("{{")
+            && !template.contains("{%")
+        {
+            return Cow::Borrowed(template);
+        }
+
+        let mut output = String::with_capacity(template.len());
+        let mut text_start = 0;
+        let mut i = 0;
+        while i < template.len() {
+            let rest = &template[i..];
+            // Check the longer start first in case one delimiter is a prefix of the other.
+            let (tag_first, output_first) = (
+                rest.starts_with(&self.tag.0),
+                rest.starts_with(&self.output.0),
+            );
+            let is_tag = match (output_first, tag_first) {
+                (true, true) => self.tag.0.len() > self.output.0.len(),
+                (false, true) => true,
+                (true, false) => false,
+                (false, false) => {
+                    i += rest.chars().next().map_or(1, char::len_utf8);
+                    continue;
+                }
+            };
+            let (start, end) = if is_tag { &self.tag } else { &self.output };
+            let inner_start = i + start.len();
+            let Some(len) = markup_len(&template[inner_start..], end) else {
+                // Unterminated markup is left as text.
+                break;
+            };
+            push_text(&mut output, &template[text_start..i]);
+            let inner = &template[inner_start..inner_start + len];
+            i = inner_start + len + end.len();
+
+            if is_tag && inner.trim_matches('-').trim() == "raw" {
+                let (content, after) = self.raw_content(&template[i..]);
+                push_text(&mut output, content);
+                i = template.len() - after.len();
+            } else if is_tag {
+                output.push_str("{%");
+                output.push_str(inner);
+                output.push_str("%}");
+            } else {
+                output.push_str("{{");
+                output.push_str(inner);
+                output.push_str("}}");
+            }
+            text_start = i;
+        }
+        push_text(&mut output, &template[text_start..]);
+        Cow::Owned(output)
+    }
+
+    /// Split the text after a raw tag into its content and what follows the matching endraw tag.
+    pub(crate) fn raw_content<'t>(&self, template: &'t str) -> (&'t str, &'t str) {
+        let (start, end) = &self.tag;
+        let mut offset = 0;
+        while let Some(found) = template[offset..].find(start.as_str()) {
+            let tag_start = offset + found;
+            let inner_start = tag_start + start.len();
+            if let Some(len) = template[inner_start..].find(end.as_str()) {
+                let inner = &template[inner_start..inner_start + len];
+                if inner.trim_matches('-').trim() == "endraw" {
+                    return (
+                        &template[..tag_start],
+                        &template[inner_start + len + end.len()..],
+                    );
+                }
+            }
+            offset = inner_start;
+        }
+        (template, "")
+    }
+}
+
+/// The length of markup up to its end delimiter, skipping over quoted strings.
+pub(crate) fn markup_len(markup: &str, end: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in markup.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' => quote = Some(c),
+            None if markup[i..].starts_with(end) => return Some(i),
+            None => {}
+        }
+    }
+    None
+}
+
+/// Append literal text, replacing every `{{` and `{%` with an expression that outputs it so Liquid
+/// doesn't read it as markup. Unlike wrapping the text in `{% raw %}`, this also works for text
+/// that contains `{% endraw %}` or unterminated markup.
+fn push_text(output: &mut String, text: &str) {
+    push_escaped(output, text, ("{{", "}}"), "{%");
+}
+
+/// Append literal text, replacing every start of output or tag markup with an output expression
+/// that prints it.
+fn push_escaped(output: &mut String, text: &str, (open, close): (&str, &str), tag_open: &str) {
+    let mut rest = text;
+    while let Some(i) = rest.find(open).into_iter().chain(rest.find(tag_open)).min() {
+        // Check the longer start first in case one delimiter is a prefix of the other.
+        let marker = match (rest[i..].starts_with(open), rest[i..].starts_with(tag_open)) {
+            (true, true) if tag_open.len() > open.len() => tag_open,
+            (true, _) => open,
+            (false, _) => tag_open,
+        };
+        let quote = if marker.contains('\'') { '"' } else { '\'' };
+        output.push_str(&rest[..i]);
+        output.push_str(open);
+        output.push(' ');
+        output.push(quote);
+        output.push_str(marker);
+        output.push(quote);
+        output.push(' ');
+        output.push_str(close);
+        rest = &rest[i + marker.len()..];
+    }
+    output.push_str(rest);
+}
+
+/// The delimiters of the templates in a render, if they aren't the standard ones.
+#[derive(Default)]
+pub(crate) struct DelimiterRegister {
+    delimiters: Option<Delimiters>,
+}
+
+impl DelimiterRegister {
+    /// Rewrite a template to use the standard delimiters.
+    pub(crate) fn translate<'t>(&self, template: &'t str) -> Cow<'t, str> {
+        self.delimiters
+            .as_ref()
+            .map_or(Cow::Borrowed(template), |d| d.translate(template))
+    }
+}
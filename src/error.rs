@@ -10,4 +10,19 @@ pub enum Error {
     /// Tried to use a u64 value in a Liquid template, which isn't supported by the Liquid library.
     #[error("Liquid templates do not support u64 values as of right now")]
     U64,
+    /// Thrown when a string in the template isn't valid Liquid.
+    #[error("Invalid template at {path:?}: {source}")]
+    InvalidTemplate {
+        /// The [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901) to the invalid string.
+        path: String,
+        /// Why the string couldn't be parsed.
+        source: liquid::Error,
+    },
+    /// Thrown when a rendered template can't be converted into the type a `LiquidJsonValue`
+    /// renders into.
+    #[error("Rendered template doesn't match the expected type: {0}")]
+    Deserialize(serde_json::Error),
+    /// Thrown when extracting variables from a document the template couldn't have rendered.
+    #[error("Document doesn't match the template: {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+    Mismatch(Vec<crate::Mismatch>),
 }
@@ -1,25 +1,535 @@
 mod each;
 #[cfg(feature = "serde")]
 mod json;
+mod json_escape;
+mod jsonpath;
 mod output;
+mod pointer;
+mod to_json;
 
 mod base64_decode;
 mod base64_encode;
+mod hex_decode;
+mod hex_encode;
+mod hmac_sha256;
+mod md5;
+mod percent_decode;
+mod percent_encode;
+mod sha1;
+mod sha256;
 
+mod deep_merge;
+mod entries;
+mod from_entries;
+mod keys;
+mod merge;
+mod omit;
+mod pick;
+mod set;
+mod values;
+
+mod random_int;
+mod sample;
+mod shuffle;
+mod uuid_v4;
+mod uuid_v5;
+
+mod regex_captures;
+mod regex_match;
+mod regex_replace;
+
+mod camel_case;
+mod convert_keys;
+mod kebab_case;
+mod pascal_case;
+mod slugify;
+mod snake_case;
+mod title_case;
+
+mod average;
+mod count_by;
+mod max;
+mod min;
+mod sum;
+
+mod chunk;
+mod flatten;
+mod group_by;
+mod index_by;
+mod range;
+mod sort_by;
+mod zip;
+
+mod coalesce;
+
+mod add_duration;
+mod date;
+mod in_timezone;
+mod parse_date;
+mod to_iso8601;
+mod to_unix;
+
+pub(crate) use add_duration::AddDuration;
+pub(crate) use average::Average;
 pub(crate) use base64_decode::Base64Decode;
 pub(crate) use base64_encode::Base64Encode;
+pub(crate) use camel_case::CamelCase;
+pub(crate) use chunk::Chunk;
+pub(crate) use coalesce::Coalesce;
+pub(crate) use convert_keys::ConvertKeys;
+pub(crate) use count_by::CountBy;
+pub(crate) use date::Date;
+pub(crate) use deep_merge::DeepMerge;
 pub(crate) use each::Each;
+pub(crate) use entries::Entries;
+pub(crate) use flatten::Flatten;
+pub(crate) use from_entries::FromEntries;
+pub(crate) use group_by::GroupBy;
+pub(crate) use hex_decode::HexDecode;
+pub(crate) use hex_encode::HexEncode;
+pub(crate) use hmac_sha256::HmacSha256;
+pub(crate) use in_timezone::InTimezone;
+pub(crate) use index_by::IndexBy;
 #[cfg(feature = "serde")]
 pub(crate) use json::Json;
+pub(crate) use json_escape::JsonEscape;
+pub(crate) use jsonpath::JsonPath;
+pub(crate) use kebab_case::KebabCase;
+pub(crate) use keys::Keys;
+pub(crate) use max::Max;
+pub(crate) use md5::Md5;
+pub(crate) use merge::Merge;
+pub(crate) use min::Min;
+pub(crate) use omit::Omit;
 pub(crate) use output::Output;
+pub(crate) use parse_date::ParseDate;
+pub(crate) use pascal_case::PascalCase;
+pub(crate) use percent_decode::PercentDecode;
+pub(crate) use percent_encode::PercentEncode;
+pub(crate) use pick::Pick;
+pub(crate) use pointer::Pointer;
+pub(crate) use random_int::RandomInt;
+pub(crate) use range::Range;
+pub(crate) use regex_captures::RegexCaptures;
+pub(crate) use regex_match::RegexMatch;
+pub(crate) use regex_replace::RegexReplace;
+pub(crate) use sample::Sample;
+pub(crate) use set::Set;
+pub(crate) use sha1::Sha1;
+pub(crate) use sha256::Sha256;
+pub(crate) use shuffle::Shuffle;
+pub(crate) use slugify::Slugify;
+pub(crate) use snake_case::SnakeCase;
+pub(crate) use sort_by::SortBy;
+pub(crate) use sum::Sum;
+pub(crate) use title_case::TitleCase;
+pub(crate) use to_iso8601::ToIso8601;
+pub(crate) use to_json::ToJson;
+pub(crate) use to_unix::ToUnix;
+pub(crate) use uuid_v4::UuidV4;
+pub(crate) use uuid_v5::UuidV5;
+pub(crate) use values::Values;
+pub(crate) use zip::Zip;
+
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+use base64::engine::{general_purpose, GeneralPurpose};
+use base64::Engine;
+use liquid_core::model::{DateTime, KString, KStringCow};
+use liquid_core::parser::FilterArguments;
+use liquid_core::{Expression, ObjectView, Result, Runtime, Value, ValueView};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use time::format_description::well_known::{Iso8601, Rfc3339};
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+use crate::environment::ClockRegister;
 
 pub(crate) fn invalid_input<S>(cause: S) -> liquid_core::Error
 where
-    S: Into<liquid_core::model::KString>,
+    S: Into<KString>,
 {
     liquid_core::Error::with_msg("Invalid input").context("cause", cause)
 }
 
+pub(crate) fn invalid_argument<A, C>(argument: A, cause: C) -> liquid_core::Error
+where
+    A: Into<KString>,
+    C: Into<KString>,
+{
+    liquid_core::Error::with_msg("Invalid argument")
+        .context("argument", argument)
+        .context("cause", cause)
+}
+
+/// Looks up a dotted property path like `address.city` on a value. Segments that are numbers
+/// index into arrays.
+pub(crate) fn property<'v>(value: &'v dyn ValueView, path: &str) -> Option<&'v dyn ValueView> {
+    path.split('.').try_fold(value, |value, key| {
+        value.as_object().map_or_else(
+            || value.as_array()?.get(key.parse().ok()?),
+            |object| object.get(key),
+        )
+    })
+}
+
+/// Reads the elements of an array, or the given property of each element, skipping nil and
+/// missing values.
+pub(crate) fn present_values<'v>(
+    input: &'v dyn ValueView,
+    path: Option<&str>,
+) -> Result<Vec<&'v dyn ValueView>> {
+    let array = input
+        .as_array()
+        .ok_or_else(|| invalid_input("Array expected"))?;
+    Ok(array
+        .values()
+        .filter_map(|v| path.map_or(Some(v), |path| property(v, path)))
+        .filter(|v| !v.is_nil())
+        .collect())
+}
+
+/// A whole or fractional number read from a Liquid value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Number {
+    Integer(i64),
+    Float(f64),
+}
+
+impl Number {
+    /// Reads a number from a value, accepting strings that hold a number like the stdlib math
+    /// filters do.
+    pub(crate) fn from_value(value: &dyn ValueView) -> Result<Self> {
+        let scalar = value.as_scalar();
+        scalar
+            .as_ref()
+            .and_then(|s| s.to_integer())
+            .map(Self::Integer)
+            .or_else(|| scalar.and_then(|s| s.to_float()).map(Self::Float))
+            .ok_or_else(|| invalid_input("Number expected"))
+    }
+
+    pub(crate) fn to_f64(self) -> f64 {
+        match self {
+            Self::Integer(n) => n as f64,
+            Self::Float(n) => n,
+        }
+    }
+
+    pub(crate) fn into_value(self) -> Value {
+        match self {
+            Self::Integer(n) => Value::scalar(n),
+            Self::Float(n) => Value::scalar(n),
+        }
+    }
+}
+
+/// A naming convention the case filters convert words to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Case {
+    Snake,
+    Camel,
+    Pascal,
+    Kebab,
+    Title,
+}
+
+impl Case {
+    /// Looks up a case by the name of its filter, e.g. `snake_case`.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "snake_case" => Some(Self::Snake),
+            "camel_case" => Some(Self::Camel),
+            "pascal_case" => Some(Self::Pascal),
+            "kebab_case" => Some(Self::Kebab),
+            "title_case" => Some(Self::Title),
+            _ => None,
+        }
+    }
+
+    /// Converts a string in any of the supported cases, or a mix of them, to this case.
+    pub(crate) fn convert(self, input: &str) -> String {
+        let words = case_words(input);
+        match self {
+            Self::Snake => join_words(&words, "_", str::to_lowercase),
+            Self::Kebab => join_words(&words, "-", str::to_lowercase),
+            Self::Pascal => join_words(&words, "", capitalize),
+            Self::Title => join_words(&words, " ", capitalize),
+            Self::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Splits a string into words at non-alphanumeric characters and at changes of case, so
+/// `HTTPServer_name` becomes `HTTP`, `Server` and `name`.
+fn case_words(input: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut chars = input.char_indices().peekable();
+    let mut prev: Option<char> = None;
+    while let Some((i, c)) = chars.next() {
+        if !c.is_alphanumeric() {
+            if let Some(s) = start.take() {
+                words.push(&input[s..i]);
+            }
+            prev = None;
+            continue;
+        }
+        if let (Some(s), Some(p)) = (start, prev) {
+            let next_lower = chars.peek().is_some_and(|(_, n)| n.is_lowercase());
+            let boundary = c.is_uppercase()
+                && (p.is_lowercase() || p.is_numeric() || (p.is_uppercase() && next_lower));
+            if boundary {
+                words.push(&input[s..i]);
+                start = Some(i);
+            }
+        }
+        start.get_or_insert(i);
+        prev = Some(c);
+    }
+    if let Some(s) = start {
+        words.push(&input[s..]);
+    }
+    words
+}
+
+fn join_words(words: &[&str], separator: &str, convert: fn(&str) -> String) -> String {
+    words
+        .iter()
+        .map(|word| convert(word))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect()
+    })
+}
+
+static SINGLE_EXPRESSION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\{\{-?((?s).*?)-?\}\}$").unwrap());
+
+/// Gets the expression of a template that is a single `{{ expression }}`, if it is one.
+pub(crate) fn single_expression(template: &str) -> Option<&str> {
+    let expression = SINGLE_EXPRESSION
+        .captures(template)?
+        .get(1)?
+        .as_str()
+        .trim();
+    (!expression.contains("{{") && !expression.contains("}}")).then_some(expression)
+}
+
+/// Gets the name of the last filter an expression applies, ignoring `|` inside quoted strings.
+pub(crate) fn last_filter(expression: &str) -> Option<&str> {
+    let mut quote = None;
+    let mut last_pipe = None;
+    for (i, c) in expression.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' => quote = Some(c),
+            None if c == '|' => last_pipe = Some(i),
+            None => {}
+        }
+    }
+    let filter = expression[last_pipe? + 1..].trim_start();
+    let end = filter
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(filter.len());
+    Some(&filter[..end])
+}
+
+/// Finds the end of the Liquid tag at the start of `template`, skipping quoted strings.
+pub(crate) fn tag_end(template: &str, close: &str) -> usize {
+    let mut quote = None;
+    for (i, c) in template.char_indices().skip(2) {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' => quote = Some(c),
+            None if template[i..].starts_with(close) => return i + close.len(),
+            None => {}
+        }
+    }
+    template.len()
+}
+
+/// The most patterns kept compiled at once. The cache is emptied when it fills up, so templates
+/// that build patterns from data can't grow it without bound.
+const REGEX_CACHE_SIZE: usize = 256;
+
+static REGEX_CACHE: Lazy<Mutex<HashMap<String, Regex>>> = Lazy::new(Default::default);
+
+/// Compiles a regular expression, reusing the compiled pattern across filters and renders.
+pub(crate) fn cached_regex(pattern: &str) -> Result<Regex> {
+    let mut cache = REGEX_CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(regex) = cache.get(pattern) {
+        return Ok(regex.clone());
+    }
+    let regex = Regex::new(pattern).map_err(|e| invalid_argument("pattern", e.to_string()))?;
+    if cache.len() >= REGEX_CACHE_SIZE {
+        cache.clear();
+    }
+    cache.insert(pattern.to_owned(), regex.clone());
+    Ok(regex)
+}
+
+/// Looks up a base64 engine by alphabet name, defaulting to the standard padded alphabet.
+pub(crate) fn base64_engine(alphabet: Option<&str>) -> Result<&'static GeneralPurpose> {
+    match alphabet {
+        None | Some("standard") => Ok(&general_purpose::STANDARD),
+        Some("url_safe") => Ok(&general_purpose::URL_SAFE),
+        Some("no_pad") => Ok(&general_purpose::STANDARD_NO_PAD),
+        Some("url_safe_no_pad") => Ok(&general_purpose::URL_SAFE_NO_PAD),
+        Some(_) => Err(invalid_argument(
+            "alphabet",
+            "Expected `standard`, `url_safe`, `no_pad` or `url_safe_no_pad`",
+        )),
+    }
+}
+
+/// Gets the bytes of a value, reading arrays as lists of bytes and anything else as a string.
+pub(crate) fn input_bytes(input: &dyn ValueView) -> Result<Vec<u8>> {
+    let Some(array) = input.as_array() else {
+        return Ok(input.to_kstr().as_bytes().to_vec());
+    };
+    array
+        .values()
+        .map(|b| {
+            b.as_scalar()
+                .and_then(|b| b.to_integer())
+                .and_then(|b| u8::try_from(b).ok())
+                .ok_or_else(|| invalid_input("Array of bytes expected"))
+        })
+        .collect()
+}
+
+/// Converts decoded bytes into a `string` (the default), a `hex` string or an array of `bytes`.
+pub(crate) fn decoded_value(decoded: Vec<u8>, format: Option<&str>) -> Result<Value> {
+    match format {
+        None | Some("string") => {
+            let decoded = String::from_utf8(decoded)
+                .map_err(|e| liquid_core::Error::with_msg(e.to_string()))?;
+            Ok(Value::Scalar(decoded.into()))
+        }
+        Some("hex") => Ok(Value::Scalar(hex::encode(decoded).into())),
+        Some("bytes") => Ok(Value::array(
+            decoded.into_iter().map(|b| Value::scalar(i64::from(b))),
+        )),
+        Some(_) => Err(invalid_argument(
+            "format",
+            "Expected `string`, `hex` or `bytes`",
+        )),
+    }
+}
+
+/// Encodes the bytes of a digest as `hex` (the default) or `base64`.
+pub(crate) fn encode_digest(digest: &[u8], encoding: Option<&str>) -> Result<String> {
+    match encoding {
+        None | Some("hex") => Ok(hex::encode(digest)),
+        Some("base64") => Ok(general_purpose::STANDARD.encode(digest)),
+        Some(_) => Err(invalid_argument("encoding", "Expected `hex` or `base64`")),
+    }
+}
+
+/// Hashes the bytes of a value with `D`, reading them like [input_bytes], and encodes the digest
+/// like [encode_digest].
+pub(crate) fn digest_value<D: sha2::Digest>(
+    input: &dyn ValueView,
+    encoding: Option<&str>,
+) -> Result<Value> {
+    let digest = D::digest(input_bytes(input)?);
+    let encoded = encode_digest(&digest, encoding)?;
+
+    Ok(Value::Scalar(encoded.into()))
+}
+
+/// Reads a date from a date value, a unix timestamp, `now`, or an ISO 8601 or Liquid formatted
+/// date string. Dates without an offset are read as UTC.
+pub(crate) fn input_date(input: &dyn ValueView, runtime: &dyn Runtime) -> Result<OffsetDateTime> {
+    let scalar = input
+        .as_scalar()
+        .ok_or_else(|| invalid_input("Date expected"))?;
+    let date = if scalar.type_name() == "string" {
+        parse_date(scalar.to_kstr().as_str(), runtime)
+    } else if let Some(timestamp) = scalar.to_integer() {
+        OffsetDateTime::from_unix_timestamp(timestamp).ok()
+    } else if let Some(date) = scalar.to_date_time() {
+        Some(*date)
+    } else {
+        scalar.to_date().map(|date| date.midnight().assume_utc())
+    };
+    date.ok_or_else(|| invalid_input("Date expected"))
+}
+
+fn parse_date(input: &str, runtime: &dyn Runtime) -> Option<OffsetDateTime> {
+    let input = input.trim();
+    if input.eq_ignore_ascii_case("now") || input.eq_ignore_ascii_case("today") {
+        return Some(runtime.registers().get_mut::<ClockRegister>().now());
+    }
+    OffsetDateTime::parse(input, &Rfc3339)
+        .or_else(|_| OffsetDateTime::parse(input, &Iso8601::DEFAULT))
+        .or_else(|_| PrimitiveDateTime::parse(input, &Iso8601::DEFAULT).map(|d| d.assume_utc()))
+        .or_else(|_| time::Date::parse(input, &Iso8601::DEFAULT).map(|d| d.midnight().assume_utc()))
+        .ok()
+        .or_else(|| DateTime::from_str(input).map(|date| *date))
+}
+
+/// Wraps a date in Liquid's date type so it works with the stdlib date filters.
+pub(crate) fn date_value(date: OffsetDateTime) -> Value {
+    // Liquid's `DateTime` can only be created from the current time or a string, but it derefs to
+    // the date it wraps.
+    let mut value = DateTime::now();
+    *value = date;
+    Value::scalar(value)
+}
+
+/// Collects the positional arguments of a filter that accepts any number of them.
+pub(crate) fn variadic_args(mut arguments: FilterArguments<'_>) -> Result<Vec<Expression>> {
+    if let Some((name, _)) = arguments.keyword.next() {
+        return Err(liquid_core::Error::with_msg(format!(
+            "Unexpected named argument `{}`",
+            name
+        )));
+    }
+    Ok(arguments.positional.collect())
+}
+
+/// Liquid objects are unordered, so entries are sorted by key wherever their order is observable.
+pub(crate) fn sorted_entries(object: &dyn ObjectView) -> Vec<(KStringCow<'_>, &dyn ValueView)> {
+    let mut entries: Vec<_> = object.iter().collect();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    entries
+}
+
+/// Flattens string and array arguments into a list of keys.
+pub(crate) fn key_list<'v>(values: impl Iterator<Item = &'v dyn ValueView>) -> Vec<KString> {
+    values
+        .flat_map(|v| {
+            v.as_array().map_or_else(
+                || vec![v.to_kstr().into_owned()],
+                |array| array.values().map(|v| v.to_kstr().into_owned()).collect(),
+            )
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::LiquidJson;
@@ -0,0 +1,142 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+use time::Duration;
+
+use super::{date_value, input_date, invalid_argument, invalid_input};
+
+#[derive(Debug, FilterParameters)]
+struct AddDurationArgs {
+    #[parameter(
+        description = "The duration to add, e.g. `1h30m` or `-2d`. Units are `w`, `d`, `h`, `m`, `s` and `ms`.",
+        arg_type = "str"
+    )]
+    duration: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "add_duration",
+    description = "Adds a duration to a date.",
+    parameters(AddDurationArgs),
+    parsed(AddDurationFilter)
+)]
+pub(crate) struct AddDuration;
+
+impl ParseFilter for AddDuration {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = AddDurationArgs::from_args(arguments)?;
+
+        Ok(Box::new(AddDurationFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "add_duration"]
+struct AddDurationFilter {
+    #[parameters]
+    args: AddDurationArgs,
+}
+
+impl std::fmt::Debug for AddDurationFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AddDurationFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for AddDurationFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let date = input_date(input, runtime)?;
+        let duration = parse_duration(&args.duration)
+            .ok_or_else(|| invalid_argument("duration", "Invalid duration"))?;
+        let date = date
+            .checked_add(duration)
+            .ok_or_else(|| invalid_input("Date out of range"))?;
+
+        Ok(date_value(date))
+    }
+}
+
+/// Parses a duration made of one or more `<number><unit>` parts, optionally preceded by a sign.
+fn parse_duration(input: &str) -> Option<Duration> {
+    let input = input.trim();
+    let (negative, mut rest) = match input.as_bytes().first()? {
+        b'-' => (true, &input[1..]),
+        b'+' => (false, &input[1..]),
+        _ => (false, input),
+    };
+    if rest.is_empty() {
+        return None;
+    }
+    let mut total = Duration::ZERO;
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+        let amount: i64 = rest[..digits].parse().ok()?;
+        rest = &rest[digits..];
+        let unit = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let part = match &rest[..unit] {
+            "w" => amount.checked_mul(604_800).map(Duration::seconds),
+            "d" => amount.checked_mul(86_400).map(Duration::seconds),
+            "h" => amount.checked_mul(3_600).map(Duration::seconds),
+            "m" => amount.checked_mul(60).map(Duration::seconds),
+            "s" => Some(Duration::seconds(amount)),
+            "ms" => Some(Duration::milliseconds(amount)),
+            _ => None,
+        }?;
+        rest = &rest[unit..];
+        total = total.checked_add(part)?;
+    }
+    Some(if negative { -total } else { total })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"d":"{{ at | add_duration: '1h' | to_iso8601 }}"}), json!({"at": "2024-02-03T04:05:06Z"}), json!({"d": "2024-02-03T05:05:06Z"}))]
+    #[case(json!({"d":"{{ at | add_duration: '1h30m' | to_iso8601 }}"}), json!({"at": "2024-02-03T04:05:06Z"}), json!({"d": "2024-02-03T05:35:06Z"}))]
+    #[case(json!({"d":"{{ at | add_duration: '-2d' | to_iso8601 }}"}), json!({"at": "2024-03-01T00:00:00Z"}), json!({"d": "2024-02-28T00:00:00Z"}))]
+    #[case(json!({"d":"{{ at | add_duration: '1w500ms' | to_iso8601 }}"}), json!({"at": "2024-02-03T04:05:06Z"}), json!({"d": "2024-02-10T04:05:06.5Z"}))]
+    #[case(json!({"d":"{{ at | add_duration: '30s' | to_iso8601 }}"}), json!({"at": "2024-02-03T04:05:06+02:00"}), json!({"d": "2024-02-03T04:05:36+02:00"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!("{{ '2024-02-03' | add_duration: '' }}"))]
+    #[case(json!("{{ '2024-02-03' | add_duration: '1' }}"))]
+    #[case(json!("{{ '2024-02-03' | add_duration: 'h' }}"))]
+    #[case(json!("{{ '2024-02-03' | add_duration: '1y' }}"))]
+    #[case(json!("{{ '2024-02-03' | add_duration: '1h-1m' }}"))]
+    fn invalid(#[case] template: Value) {
+        let tmpl = LiquidJson::new(template);
+        assert!(tmpl.render(&json!({})).is_err());
+    }
+}
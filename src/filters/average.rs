@@ -0,0 +1,109 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_input, present_values, Number};
+
+#[derive(Debug, FilterParameters)]
+struct AverageArgs {
+    #[parameter(
+        description = "The property of each element to average, e.g. `price` or `totals.net`.",
+        arg_type = "str"
+    )]
+    property: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "average",
+    description = "Computes the mean of the numbers in an array, or of a property of each element.",
+    parameters(AverageArgs),
+    parsed(AverageFilter)
+)]
+pub(crate) struct Average;
+
+impl ParseFilter for Average {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = AverageArgs::from_args(arguments)?;
+
+        Ok(Box::new(AverageFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "average"]
+struct AverageFilter {
+    #[parameters]
+    args: AverageArgs,
+}
+
+impl std::fmt::Debug for AverageFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AverageFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for AverageFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let values = present_values(input, args.property.as_deref())?;
+        if values.is_empty() {
+            return Ok(Value::Nil);
+        }
+        let mut total = 0.0;
+        for value in &values {
+            total += Number::from_value(*value)?.to_f64();
+        }
+
+        if !total.is_finite() {
+            return Err(invalid_input("Average is too large"));
+        }
+
+        Ok(Value::scalar(total / values.len() as f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ nums | average | output }}"}), json!({"nums": [1, 2, 3, 4]}), json!({"this":2.5}))]
+    #[case(json!({"this":"{{ nums | average | output }}"}), json!({"nums": [2, null, 4]}), json!({"this":3.0}))]
+    #[case(json!({"this":"{{ nums | average | output }}"}), json!({"nums": []}), json!({"this":null}))]
+    #[case(json!({"this":"{{ items | average: 'rating' | output }}"}), json!({"items": [{"rating": 4}, {"rating": 5}]}), json!({"this":4.5}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!({"nums": ["n/a"]}))]
+    #[case(json!({"nums": [1e308, 1e308]}))]
+    fn invalid(#[case] data: Value) {
+        let tmpl = LiquidJson::new(json!("{{ nums | average | output }}"));
+        assert!(tmpl.render(&data).is_err());
+    }
+}
@@ -1,39 +1,76 @@
 use base64::Engine;
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
 use liquid_core::Result;
 use liquid_core::Runtime;
-use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
 use liquid_core::{Value, ValueView};
 
-#[derive(Clone, ParseFilter, FilterReflection)]
+use super::{base64_engine, decoded_value};
+
+#[derive(Debug, FilterParameters)]
+struct Base64DecodeArgs {
+    #[parameter(
+        description = "The base64 alphabet: `standard` (default), `url_safe`, `no_pad` or `url_safe_no_pad`.",
+        arg_type = "str"
+    )]
+    alphabet: Option<Expression>,
+    #[parameter(
+        description = "How to return the decoded data: `string` (default), `hex` or `bytes`.",
+        arg_type = "str",
+        mode = "keyword"
+    )]
+    format: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
 #[filter(
     name = "base64_decode",
     description = "Decode a base64 encoded string.",
+    parameters(Base64DecodeArgs),
     parsed(Base64DecodeFilter)
 )]
 pub(crate) struct Base64Decode;
 
-#[derive(Default, Display_filter)]
-#[name = "output"]
-struct Base64DecodeFilter;
+impl ParseFilter for Base64Decode {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = Base64DecodeArgs::from_args(arguments)?;
+
+        Ok(Box::new(Base64DecodeFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "base64_decode"]
+struct Base64DecodeFilter {
+    #[parameters]
+    args: Base64DecodeArgs,
+}
 
 impl std::fmt::Debug for Base64DecodeFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("OutputFilter").finish()
+        f.debug_struct("Base64DecodeFilter")
+            .field("args", &self.args)
+            .finish()
     }
 }
 
 impl Filter for Base64DecodeFilter {
-    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
-        let input = input.to_value();
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
 
-        let encoded = String::from_utf8(
-            base64::engine::general_purpose::STANDARD
-                .decode(&input.to_kstr())
-                .map_err(|e| liquid_core::Error::with_msg(e.to_string()))?,
-        )
-        .map_err(|e| liquid_core::Error::with_msg(e.to_string()))?;
+        let engine = base64_engine(args.alphabet.as_deref())?;
+        let decoded = engine
+            .decode(input.to_kstr().as_bytes())
+            .map_err(|e| liquid_core::Error::with_msg(e.to_string()))?;
 
-        Ok(Value::Scalar(encoded.into()))
+        decoded_value(decoded, args.format.as_deref())
     }
 }
 
@@ -47,6 +84,9 @@ mod tests {
 
     #[rstest]
     #[case(json!({"base64":"{{ 'VGhpcyBpcyBteSBtZXNzYWdl' | base64_decode }}"}), json!({}), json!({"base64": "This is my message"}))]
+    #[case(json!({"base64":"{{ '-_8' | base64_decode: 'url_safe_no_pad', format: 'hex' }}"}), json!({}), json!({"base64": "fbff"}))]
+    #[case(json!({"base64":"{{ '+/8A' | base64_decode: format: 'bytes' | output }}"}), json!({}), json!({"base64": [251, 255, 0]}))]
+    #[case(json!({"base64":"{{ '+/8A' | base64_decode: format: 'bytes' | base64_encode: 'url_safe' }}"}), json!({}), json!({"base64": "-_8A"}))]
     fn filters(
         #[case] template: Value,
         #[case] data: Value,
@@ -57,4 +97,14 @@ mod tests {
         assert_eq!(actual, expected);
         Ok(())
     }
+
+    #[rstest]
+    #[case(json!("{{ '+/8A' | base64_decode }}"))]
+    #[case(json!("{{ '-_8A' | base64_decode }}"))]
+    #[case(json!("{{ 'VGhpcw' | base64_decode }}"))]
+    #[case(json!("{{ 'VGhpcw==' | base64_decode: format: 'binary' }}"))]
+    fn invalid(#[case] template: Value) {
+        let tmpl = LiquidJson::new(template);
+        assert!(tmpl.render(&json!({})).is_err());
+    }
 }
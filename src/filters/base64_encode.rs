@@ -1,34 +1,66 @@
 use base64::Engine;
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
 use liquid_core::Result;
 use liquid_core::Runtime;
-use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
 use liquid_core::{Value, ValueView};
 
-#[derive(Clone, ParseFilter, FilterReflection)]
+use super::{base64_engine, input_bytes};
+
+#[derive(Debug, FilterParameters)]
+struct Base64EncodeArgs {
+    #[parameter(
+        description = "The base64 alphabet: `standard` (default), `url_safe`, `no_pad` or `url_safe_no_pad`.",
+        arg_type = "str"
+    )]
+    alphabet: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
 #[filter(
     name = "base64_encode",
-    description = "Encode a string as base64",
+    description = "Encode a string or an array of bytes as base64",
+    parameters(Base64EncodeArgs),
     parsed(Base64EncodeFilter)
 )]
 pub(crate) struct Base64Encode;
 
-#[derive(Default, Display_filter)]
-#[name = "output"]
-struct Base64EncodeFilter;
+impl ParseFilter for Base64Encode {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = Base64EncodeArgs::from_args(arguments)?;
+
+        Ok(Box::new(Base64EncodeFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "base64_encode"]
+struct Base64EncodeFilter {
+    #[parameters]
+    args: Base64EncodeArgs,
+}
 
 impl std::fmt::Debug for Base64EncodeFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("OutputFilter").finish()
+        f.debug_struct("Base64EncodeFilter")
+            .field("args", &self.args)
+            .finish()
     }
 }
 
 impl Filter for Base64EncodeFilter {
-    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
-        let input = input.to_value();
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
 
-        let encoded = base64::engine::general_purpose::STANDARD
-            .encode(&input.to_kstr())
-            .to_string();
+        let engine = base64_engine(args.alphabet.as_deref())?;
+        let encoded = engine.encode(input_bytes(input)?);
 
         Ok(Value::Scalar(encoded.into()))
     }
@@ -44,6 +76,10 @@ mod tests {
 
     #[rstest]
     #[case(json!({"base64":"{{ 'This is my message' | base64_encode }}"}), json!({}), json!({"base64": "VGhpcyBpcyBteSBtZXNzYWdl"}))]
+    #[case(json!({"base64":"{{ bytes | base64_encode }}"}), json!({"bytes": [251, 255, 0]}), json!({"base64": "+/8A"}))]
+    #[case(json!({"base64":"{{ bytes | base64_encode: 'url_safe' }}"}), json!({"bytes": [251, 255]}), json!({"base64": "-_8="}))]
+    #[case(json!({"base64":"{{ bytes | base64_encode: 'no_pad' }}"}), json!({"bytes": [251, 255]}), json!({"base64": "+/8"}))]
+    #[case(json!({"base64":"{{ bytes | base64_encode: 'url_safe_no_pad' }}"}), json!({"bytes": [251, 255]}), json!({"base64": "-_8"}))]
     fn filters(
         #[case] template: Value,
         #[case] data: Value,
@@ -54,4 +90,12 @@ mod tests {
         assert_eq!(actual, expected);
         Ok(())
     }
+
+    #[rstest]
+    #[case(json!("{{ 'abc' | base64_encode: 'base32' }}"), json!({}))]
+    #[case(json!("{{ bytes | base64_encode }}"), json!({"bytes": [256]}))]
+    fn invalid(#[case] template: Value, #[case] data: Value) {
+        let tmpl = LiquidJson::new(template);
+        assert!(tmpl.render(&data).is_err());
+    }
 }
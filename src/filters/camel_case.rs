@@ -0,0 +1,56 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+use super::Case;
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "camel_case",
+    description = "Converts a string to camelCase.",
+    parsed(CamelCaseFilter)
+)]
+pub(crate) struct CamelCase;
+
+#[derive(Default, Display_filter)]
+#[name = "camel_case"]
+struct CamelCaseFilter;
+
+impl std::fmt::Debug for CamelCaseFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CamelCaseFilter").finish()
+    }
+}
+
+impl Filter for CamelCaseFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        Ok(Value::scalar(Case::Camel.convert(input.to_kstr().as_str())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case("user_id", "userId")]
+    #[case("userId", "userId")]
+    #[case("UserID", "userId")]
+    #[case("HTTPServerError", "httpServerError")]
+    #[case("first-name", "firstName")]
+    #[case("SCREAMING_SNAKE", "screamingSnake")]
+    #[case("version2Name", "version2Name")]
+    #[case(" leading  spaces ", "leadingSpaces")]
+    #[case("", "")]
+    fn filters(#[case] input: &str, #[case] expected: &str) -> Result<()> {
+        let tmpl = LiquidJson::new(json!("{{ input | camel_case }}"));
+        let actual = tmpl.render(&json!({ "input": input }))?;
+        assert_eq!(actual, Value::String(expected.to_owned()));
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_argument, invalid_input};
+
+#[derive(Debug, FilterParameters)]
+struct ChunkArgs {
+    #[parameter(description = "The size of each chunk.", arg_type = "integer")]
+    size: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "chunk",
+    description = "Splits an array into arrays of the given size. The last chunk may be shorter.",
+    parameters(ChunkArgs),
+    parsed(ChunkFilter)
+)]
+pub(crate) struct Chunk;
+
+impl ParseFilter for Chunk {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = ChunkArgs::from_args(arguments)?;
+
+        Ok(Box::new(ChunkFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "chunk"]
+struct ChunkFilter {
+    #[parameters]
+    args: ChunkArgs,
+}
+
+impl std::fmt::Debug for ChunkFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChunkFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for ChunkFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let size = usize::try_from(args.size)
+            .ok()
+            .filter(|size| *size > 0)
+            .ok_or_else(|| invalid_argument("size", "Positive number expected"))?;
+        let input = input
+            .as_array()
+            .ok_or_else(|| invalid_input("Array expected"))?;
+        let elements: Vec<_> = input.values().map(|v| v.to_value()).collect();
+
+        Ok(Value::array(
+            elements
+                .chunks(size)
+                .map(|chunk| Value::array(chunk.to_vec())),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ arr | chunk: 2 | output }}"}), json!({"arr": [1, 2, 3, 4, 5]}), json!({"this":[[1,2],[3,4],[5]]}))]
+    #[case(json!({"this":"{{ arr | chunk: 5 | output }}"}), json!({"arr": [1, 2]}), json!({"this":[[1,2]]}))]
+    #[case(json!({"this":"{{ arr | chunk: 3 | output }}"}), json!({"arr": []}), json!({"this":[]}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!("{{ arr | chunk: 0 }}"), json!({"arr": [1]}))]
+    #[case(json!("{{ arr | chunk: -1 }}"), json!({"arr": [1]}))]
+    #[case(json!("{{ arr | chunk: 2 }}"), json!({"arr": "12"}))]
+    fn invalid(#[case] template: Value, #[case] data: Value) {
+        let tmpl = LiquidJson::new(template);
+        assert!(tmpl.render(&data).is_err());
+    }
+}
@@ -0,0 +1,92 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+use super::variadic_args;
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "coalesce",
+    description = "Produces the first of the input and the arguments that isn't nil. Unlike `default`, `false` and empty values are kept.",
+    parsed(CoalesceFilter)
+)]
+pub(crate) struct Coalesce;
+
+impl ParseFilter for Coalesce {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let fallbacks = variadic_args(arguments)?;
+
+        Ok(Box::new(CoalesceFilter { fallbacks }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(Display_filter)]
+#[name = "coalesce"]
+struct CoalesceFilter {
+    fallbacks: Vec<Expression>,
+}
+
+impl std::fmt::Debug for CoalesceFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CoalesceFilter")
+            .field("fallbacks", &self.fallbacks)
+            .finish()
+    }
+}
+
+impl Filter for CoalesceFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        if !input.is_nil() {
+            return Ok(input.to_value());
+        }
+        // Missing variables count as nil, so fallbacks don't have to exist. Later fallbacks aren't
+        // looked up once one is found.
+        let value = self
+            .fallbacks
+            .iter()
+            .filter_map(|fallback| fallback.try_evaluate(runtime))
+            .find(|value| !value.is_nil())
+            .map_or(Value::Nil, |value| value.to_value());
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ retries | coalesce: 3 }}"}), json!({}), json!({"this":3}))]
+    #[case(json!({"this":"{{ retries | coalesce: 3 }}"}), json!({"retries": 0}), json!({"this":0}))]
+    #[case(json!({"this":"{{ x | coalesce: empty }}"}), json!({}), json!({"this":""}))]
+    #[case(json!({"this":"{{ enabled | coalesce: true }}"}), json!({"enabled": false}), json!({"this":false}))]
+    #[case(json!({"this":"{{ name | coalesce: 'anon' }}"}), json!({"name": ""}), json!({"this":""}))]
+    #[case(json!({"this":"{{ a | coalesce: b, c, 'none' }}"}), json!({"a": null, "c": {"id": 1}}), json!({"this":{"id":1}}))]
+    #[case(json!({"this":"{{ a | coalesce: b }}"}), json!({}), json!({"this":null}))]
+    #[case(json!({"this":"{{ a | coalesce }}"}), json!({"a": [1]}), json!({"this":[1]}))]
+    #[case(json!({"this":"{{ a | coalesce: 'x|y' }}"}), json!({}), json!({"this":"x|y"}))]
+    #[case(json!({"this":"retries: {{ retries | coalesce: 3 }}"}), json!({}), json!({"this":"retries: 3"}))]
+    #[case(json!({"this":"{{ retries | coalesce: 3 | plus: 1 }}"}), json!({}), json!({"this":"4"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
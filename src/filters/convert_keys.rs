@@ -0,0 +1,131 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_argument, invalid_input, sorted_entries, Case};
+
+#[derive(Debug, FilterParameters)]
+struct ConvertKeysArgs {
+    #[parameter(
+        description = "The case to convert keys to: `snake_case`, `camel_case`, `pascal_case`, `kebab_case` or `title_case`.",
+        arg_type = "str"
+    )]
+    case: Expression,
+    #[parameter(
+        description = "Also convert the keys of nested objects, including those inside arrays.",
+        arg_type = "bool",
+        mode = "keyword"
+    )]
+    deep: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "convert_keys",
+    description = "Converts every key of an object to the given case.",
+    parameters(ConvertKeysArgs),
+    parsed(ConvertKeysFilter)
+)]
+pub(crate) struct ConvertKeys;
+
+impl ParseFilter for ConvertKeys {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = ConvertKeysArgs::from_args(arguments)?;
+
+        Ok(Box::new(ConvertKeysFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "convert_keys"]
+struct ConvertKeysFilter {
+    #[parameters]
+    args: ConvertKeysArgs,
+}
+
+impl std::fmt::Debug for ConvertKeysFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConvertKeysFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for ConvertKeysFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let case =
+            Case::from_name(&args.case).ok_or_else(|| invalid_argument("case", "Unknown case"))?;
+        if input.as_object().is_none() {
+            return Err(invalid_input("Object expected"));
+        }
+
+        Ok(convert_keys(input, case, args.deep.unwrap_or(false)))
+    }
+}
+
+fn convert_keys(value: &dyn ValueView, case: Case, deep: bool) -> Value {
+    if let Some(object) = value.as_object() {
+        // Keys that convert to the same name collide, and the one that sorts last wins.
+        let object = sorted_entries(object)
+            .into_iter()
+            .map(|(k, v)| {
+                let v = if deep {
+                    convert_keys(v, case, deep)
+                } else {
+                    v.to_value()
+                };
+                (case.convert(k.as_str()).into(), v)
+            })
+            .collect();
+        return Value::Object(object);
+    }
+    match value.as_array() {
+        Some(array) if deep => Value::array(array.values().map(|v| convert_keys(v, case, deep))),
+        _ => value.to_value(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ user | convert_keys: 'camel_case' | output }}"}), json!({"user": {"first_name": "Jane", "home_address": {"zip_code": "12345"}}}), json!({"this":{"firstName":"Jane","homeAddress":{"zip_code":"12345"}}}))]
+    #[case(json!({"this":"{{ user | convert_keys: 'snake_case', deep: true | output }}"}), json!({"user": {"firstName": "Jane", "homeAddress": {"zipCode": "12345"}, "phoneNumbers": [{"countryCode": "1"}]}}), json!({"this":{"first_name":"Jane","home_address":{"zip_code":"12345"},"phone_numbers":[{"country_code":"1"}]}}))]
+    #[case(json!({"this":"{{ env | convert_keys: 'kebab_case' | output }}"}), json!({"env": {"API_KEY": "a", "LogLevel": "debug"}}), json!({"this":{"api-key":"a","log-level":"debug"}}))]
+    #[case(json!({"this":"{{ env | convert_keys: 'pascal_case' | output }}"}), json!({"env": {}}), json!({"this":{}}))]
+    #[case(json!({"this":"{{ u | merge: v | convert_keys: 'snake_case' | output }}"}), json!({"u": {"a_b": 1}, "v": {"aB": 2}}), json!({"this":{"a_b":1}}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!("{{ obj | convert_keys: 'upper_case' }}"), json!({"obj": {"a": 1}}))]
+    #[case(json!("{{ obj | convert_keys: 'snake_case' }}"), json!({"obj": [1]}))]
+    fn invalid(#[case] template: Value, #[case] data: Value) {
+        let tmpl = LiquidJson::new(template);
+        assert!(tmpl.render(&data).is_err());
+    }
+}
@@ -0,0 +1,104 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Object;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::present_values;
+
+#[derive(Debug, FilterParameters)]
+struct CountByArgs {
+    #[parameter(
+        description = "The property of each element to count by, e.g. `status` or `owner.team`.",
+        arg_type = "str"
+    )]
+    property: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "count_by",
+    description = "Counts how many times each value, or each value of a property, appears in an array.",
+    parameters(CountByArgs),
+    parsed(CountByFilter)
+)]
+pub(crate) struct CountBy;
+
+impl ParseFilter for CountBy {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = CountByArgs::from_args(arguments)?;
+
+        Ok(Box::new(CountByFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "count_by"]
+struct CountByFilter {
+    #[parameters]
+    args: CountByArgs,
+}
+
+impl std::fmt::Debug for CountByFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CountByFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for CountByFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let mut counts = Object::new();
+        for value in present_values(input, args.property.as_deref())? {
+            let count = counts
+                .entry(value.to_kstr().into_owned())
+                .or_insert(Value::scalar(0i64));
+            *count = Value::scalar(count.as_scalar().and_then(|c| c.to_integer()).unwrap_or(0) + 1);
+        }
+
+        Ok(Value::Object(counts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ tags | count_by | output }}"}), json!({"tags": ["a", "b", "a"]}), json!({"this":{"a":2,"b":1}}))]
+    #[case(json!({"this":"{{ orders | count_by: 'status' | output }}"}), json!({"orders": [{"status": "paid"}, {"status": "open"}, {"status": "paid"}, {}]}), json!({"this":{"paid":2,"open":1}}))]
+    #[case(json!({"this":"{{ users | count_by: 'team.name' | output }}"}), json!({"users": [{"team": {"name": "core"}}, {"team": {"name": "core"}}]}), json!({"this":{"core":2}}))]
+    #[case(json!({"this":"{{ codes | count_by | output }}"}), json!({"codes": [200, 404, 200, true]}), json!({"this":{"200":2,"404":1,"true":1}}))]
+    #[case(json!({"this":"{{ tags | count_by | output }}"}), json!({"tags": []}), json!({"this":{}}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let tmpl = LiquidJson::new(json!("{{ tags | count_by }}"));
+        assert!(tmpl.render(&json!({"tags": "a"})).is_err());
+    }
+}
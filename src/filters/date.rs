@@ -0,0 +1,88 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+use liquid_lib::stdlib;
+
+use super::{date_value, input_date};
+
+/// Wraps the stdlib `date` filter so `now` follows the render's [crate::Environment] and ISO 8601
+/// strings and unix timestamps can be formatted.
+#[derive(Clone)]
+pub(crate) struct Date {
+    inner: stdlib::Date,
+}
+
+impl Date {
+    pub(crate) fn new() -> Self {
+        Self {
+            inner: stdlib::Date,
+        }
+    }
+}
+
+impl ParseFilter for Date {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let inner = self.inner.parse(arguments)?;
+
+        Ok(Box::new(DateFilter { inner }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self.inner.reflection()
+    }
+}
+
+struct DateFilter {
+    inner: Box<dyn Filter>,
+}
+
+impl std::fmt::Debug for DateFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DateFilter")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl std::fmt::Display for DateFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl Filter for DateFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        // Leave anything that isn't a date to the stdlib filter, which returns it unchanged.
+        input_date(input, runtime).map_or_else(
+            |_| self.inner.evaluate(input, runtime),
+            |date| self.inner.evaluate(&date_value(date), runtime),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use crate::{Environment, LiquidJson};
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"d":"{{ 'now' | date: '%Y-%m-%d %H:%M' }}"}), json!({"d": "2023-11-14 22:13"}))]
+    #[case(json!({"d":"{{ '2024-02-03T04:05:06Z' | date: '%d/%m/%Y' }}"}), json!({"d": "03/02/2024"}))]
+    #[case(json!({"d":"{{ 0 | date: '%Y' }}"}), json!({"d": "1970"}))]
+    #[case(json!({"d":"{{ 'not a date' | date: '%Y' }}"}), json!({"d": "not a date"}))]
+    fn filters(#[case] template: Value, #[case] expected: Value) -> Result<()> {
+        let env = Environment::new()
+            .with_now(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render_with(&json!({}), &env)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
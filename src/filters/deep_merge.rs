@@ -0,0 +1,138 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_argument, invalid_input};
+
+#[derive(Debug, FilterParameters)]
+struct DeepMergeArgs {
+    #[parameter(
+        description = "The object to merge over the input. Nil leaves the input unchanged.",
+        arg_type = "any"
+    )]
+    other: Expression,
+    #[parameter(
+        description = "How to merge arrays found at the same key: `replace` (default) or `concat`.",
+        arg_type = "str",
+        mode = "keyword"
+    )]
+    arrays: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "deep_merge",
+    description = "Recursively merges another object into an object. Nested objects are merged key by key, any other value from the merged object replaces the original.",
+    parameters(DeepMergeArgs),
+    parsed(DeepMergeFilter)
+)]
+pub(crate) struct DeepMerge;
+
+impl ParseFilter for DeepMerge {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = DeepMergeArgs::from_args(arguments)?;
+
+        Ok(Box::new(DeepMergeFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "deep_merge"]
+struct DeepMergeFilter {
+    #[parameters]
+    args: DeepMergeArgs,
+}
+
+impl std::fmt::Debug for DeepMergeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeepMergeFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for DeepMergeFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let concat_arrays = match args.arrays.as_deref() {
+            None | Some("replace") => false,
+            Some("concat") => true,
+            Some(_) => return Err(invalid_argument("arrays", "Expected `replace` or `concat`")),
+        };
+        let input = input
+            .as_object()
+            .ok_or_else(|| invalid_input("Object expected"))?
+            .to_value();
+        if args.other.is_nil() {
+            return Ok(input);
+        }
+        let other = args
+            .other
+            .as_object()
+            .ok_or_else(|| invalid_argument("other", "Object expected"))?
+            .to_value();
+
+        Ok(deep_merge(input, other, concat_arrays))
+    }
+}
+
+fn deep_merge(base: Value, other: Value, concat_arrays: bool) -> Value {
+    match (base, other) {
+        (Value::Object(mut base), Value::Object(other)) => {
+            for (k, v) in other {
+                let merged = match base.remove(&k) {
+                    Some(existing) => deep_merge(existing, v, concat_arrays),
+                    None => v,
+                };
+                base.insert(k, merged);
+            }
+            Value::Object(base)
+        }
+        (Value::Array(mut base), Value::Array(other)) if concat_arrays => {
+            base.extend(other);
+            Value::Array(base)
+        }
+        (_, other) => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ defaults | deep_merge: config | output }}"}), json!({"defaults": {"a": 1, "b": {"c": 2, "d": 3}, "l": [1]}, "config": {"b": {"c": 4, "f": {"g": 1}}, "l": [2]}}), json!({"this":{"a": 1, "b": {"c": 4, "d": 3, "f": {"g": 1}}, "l": [2]}}))]
+    #[case(json!({"this":"{{ defaults | deep_merge: config, arrays: 'concat' | output }}"}), json!({"defaults": {"l": [1], "n": {"l": ["a"]}}, "config": {"l": [2], "n": {"l": ["b"]}}}), json!({"this":{"l": [1, 2], "n": {"l": ["a", "b"]}}}))]
+    #[case(json!({"this":"{{ defaults | deep_merge: config | output }}"}), json!({"defaults": {"a": {"b": 1}}, "config": {"a": null}}), json!({"this":{"a": null}}))]
+    #[case(json!({"this":"{{ defaults | deep_merge: nil | output }}"}), json!({"defaults": {"a": 1}}), json!({"this":{"a": 1}}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_array_mode() {
+        let tmpl = LiquidJson::new(json!("{{ a | deep_merge: a, arrays: 'zip' }}"));
+        assert!(tmpl.render(&json!({"a": {}})).is_err());
+    }
+}
@@ -1,5 +1,9 @@
-use crate::PARSER;
+use std::borrow::Cow;
+
+use crate::environment::DelimiterRegister;
+use crate::{output_value, OPTIONS};
 use liquid_core::parser::FilterArguments;
+use liquid_core::runtime::{GlobalFrame, StackFrame};
 use liquid_core::Expression;
 use liquid_core::Object;
 use liquid_core::Result;
@@ -7,9 +11,12 @@ use liquid_core::Runtime;
 use liquid_core::{
     Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
 };
+use liquid_core::{Renderable, Template};
 use liquid_core::{Value, ValueView};
 
-use super::invalid_input;
+use super::{
+    invalid_argument, invalid_input, last_filter, single_expression, sorted_entries, tag_end,
+};
 
 #[derive(Debug, FilterParameters)]
 struct EachArgs {
@@ -18,12 +25,24 @@ struct EachArgs {
         arg_type = "str"
     )]
     template: Expression,
+    #[parameter(
+        description = "When iterating over an object, produce an object with the same keys instead of an array.",
+        arg_type = "bool",
+        mode = "keyword"
+    )]
+    keep_keys: Option<Expression>,
+    #[parameter(
+        description = "Escape interpolated values for the given format. `json` escapes values inside JSON strings and encodes the rest as JSON, so the result always parses.",
+        arg_type = "str",
+        mode = "keyword"
+    )]
+    escape: Option<Expression>,
 }
 
 #[derive(Clone, FilterReflection)]
 #[filter(
     name = "each",
-    description = "Iterates over an array, applying a template to each element.",
+    description = "Iterates over an array or object, applying a template to each element.",
     parameters(EachArgs),
     parsed(EachFilter)
 )]
@@ -66,20 +85,207 @@ impl Filter for EachFilter {
     fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
         let args = self.args.evaluate(runtime)?;
 
-        let template = PARSER.parse(&args.template)?;
+        let template = runtime
+            .registers()
+            .get_mut::<DelimiterRegister>()
+            .translate(&args.template);
+        let template = match args.escape.as_deref() {
+            None => template,
+            Some("json") => Cow::Owned(json_escaped_template(&template)),
+            Some(_) => return Err(invalid_argument("escape", "Unknown format")),
+        };
+        let template = Template::new(liquid_core::parser::parse(
+            &typed_template(&template),
+            &OPTIONS,
+        )?);
+
+        if let Some(object) = input.as_object() {
+            let entries = sorted_entries(object).into_iter().map(|(k, v)| {
+                let key = k.into_owned();
+                let mut scope = Object::new();
+                scope.insert("key".into(), Value::scalar(key.clone()));
+                scope.insert("value".into(), v.to_value());
+                Ok((key, render_element(&template, &scope, runtime)?))
+            });
+            return if args.keep_keys.unwrap_or(false) {
+                Ok(Value::Object(entries.collect::<Result<_>>()?))
+            } else {
+                let output: Vec<_> = entries
+                    .map(|entry| entry.map(|(_, v)| v))
+                    .collect::<Result<_>>()?;
+                Ok(Value::array(output))
+            };
+        }
 
         let input = input
             .as_array()
-            .ok_or_else(|| invalid_input("Array expected"))?;
+            .ok_or_else(|| invalid_input("Array or object expected"))?;
 
         let output: Vec<_> = input
             .values()
             .map(|x| {
-                let mut global = Object::new();
-                global.insert("el".into(), x.to_value());
-                Ok(Value::Scalar(template.render(&global)?.into()))
+                let mut scope = Object::new();
+                scope.insert("el".into(), x.to_value());
+                render_element(&template, &scope, runtime)
             })
             .collect::<Result<_, _>>()?;
         Ok(Value::array(output))
     }
 }
+
+fn render_element(template: &Template, scope: &Object, runtime: &dyn Runtime) -> Result<Value> {
+    // Layer the element's variables over the enclosing runtime so outer globals stay visible, and
+    // sandbox any globals the sub-template sets so they don't leak out of the iteration.
+    let runtime = GlobalFrame::new(StackFrame::new(runtime, scope));
+    let mut output = Vec::new();
+    template.render_to(&mut output, &runtime)?;
+    if let Some(value) = output_value(&runtime) {
+        return Ok(value);
+    }
+    let output = String::from_utf8(output).unwrap();
+    Ok(Value::Scalar(output.into()))
+}
+
+/// If the template is a single `{{ expression }}`, mark its result as the output so the element
+/// keeps its type rather than being rendered to a string.
+fn typed_template(template: &str) -> String {
+    match single_expression(template) {
+        Some(expression) if last_filter(expression) != Some("output") => {
+            format!("{{{{ {} | output }}}}", expression)
+        }
+        _ => template.to_owned(),
+    }
+}
+
+/// Filters that already produce JSON text, so their output isn't escaped again.
+const JSON_FILTERS: [&str; 3] = ["json_escape", "to_json", "output"];
+
+/// Escapes every `{{ expression }}` of a JSON-shaped template: values inside a JSON string are
+/// escaped with `json_escape`, and values anywhere else are encoded with `to_json`.
+fn json_escaped_template(template: &str) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut rest = template;
+    while let Some(c) = rest.chars().next() {
+        let close = if rest.starts_with("{{") {
+            Some("}}")
+        } else if rest.starts_with("{%") {
+            Some("%}")
+        } else {
+            None
+        };
+        if let Some(close) = close {
+            let end = tag_end(rest, close);
+            let tag = &rest[..end];
+            if close == "}}" {
+                output.push_str(&escape_expression(tag, in_string));
+            } else {
+                output.push_str(tag);
+            }
+            rest = &rest[end..];
+            continue;
+        }
+        if escaped {
+            escaped = false;
+        } else if in_string && c == '\\' {
+            escaped = true;
+        } else if c == '"' {
+            in_string = !in_string;
+        }
+        output.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    output
+}
+
+fn escape_expression(tag: &str, in_string: bool) -> String {
+    let Some(inner) = tag.strip_prefix("{{").and_then(|t| t.strip_suffix("}}")) else {
+        return tag.to_owned();
+    };
+    let (open, inner) = inner
+        .strip_prefix('-')
+        .map_or(("{{", inner), |inner| ("{{-", inner));
+    let (close, inner) = inner
+        .strip_suffix('-')
+        .map_or(("}}", inner), |inner| ("-}}", inner));
+    let expression = inner.trim();
+    if last_filter(expression).is_some_and(|f| JSON_FILTERS.contains(&f)) {
+        return tag.to_owned();
+    }
+    let filter = if in_string { "json_escape" } else { "to_json" };
+    format!("{} {} | {} {}", open, expression, filter, close)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ myval | each: '{{ tenant }}/{{ el }}' | output }}"}), json!({"myval": [1,2], "tenant": "acme"}), json!({"this":["acme/1","acme/2"]}))]
+    #[case(json!({"this":"{{ myval | each: '{{ el }}' | output }}"}), json!({"myval": ["a","b"], "el": "outer"}), json!({"this":["a","b"]}))]
+    #[case(json!({"this":"{{ myval | each: '{{ el | times: 2 }}' | output }}"}), json!({"myval": [1,2]}), json!({"this":[2,4]}))]
+    #[case(json!({"this":"{{ myval | each: '{{- el.id -}}' | output }}"}), json!({"myval": [{"id":1},{"id":2}]}), json!({"this":[1,2]}))]
+    #[case(json!({"this":"{{ myval | each: '{{ el | output }}' | output }}"}), json!({"myval": [[1],[2]]}), json!({"this":[[1],[2]]}))]
+    #[case(json!({"this":"{{ myval | each: 'n{{ el | plus: 1 | output }}' | output }}"}), json!({"myval": [1,2]}), json!({"this":[2,3]}))]
+    #[case(json!({"this":"{{ myval | each: '{{ el }} and {{ el }}' | output }}"}), json!({"myval": [1,2]}), json!({"this":["1 and 1","2 and 2"]}))]
+    #[case(json!({"this":"{{ headers | each: '{{ key }}: {{ value }}' | output }}"}), json!({"headers": {"accept": "text/plain", "host": "example.com"}}), json!({"this":["accept: text/plain","host: example.com"]}))]
+    #[case(json!({"this":"{{ prices | each: '{{ value | times: 2 }}', keep_keys: true | output }}"}), json!({"prices": {"a": 1, "b": 2}}), json!({"this":{"a":2,"b":4}}))]
+    #[case(json!({"this":"{{ prices | each: '{{ key }}', keep_keys: false | output }}"}), json!({"prices": {"a": 1, "b": 2}}), json!({"this":["a","b"]}))]
+    #[case(json!({"this":"{{ myval | each: '{% assign tenant = el %}{{ tenant }}' | join: ',' }}-{{ tenant }}"}), json!({"myval": ["a","b"], "tenant": "acme"}), json!({"this":"a,b-acme"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn header_pairs() -> Result<()> {
+        let tmpl = LiquidJson::new(json!({
+            "headers": "{{ headers | each: '{\"name\":\"{{ key }}\",\"value\":\"{{ value }}\"}' | json | output }}"
+        }));
+        let actual =
+            tmpl.render(&json!({"headers": {"accept": "text/plain", "host": "example.com"}}))?;
+        assert_eq!(
+            actual,
+            json!({"headers": [{"name": "accept", "value": "text/plain"}, {"name": "host", "value": "example.com"}]})
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(r#"{"email": "{{ el.email }}"}"#, json!({"email": "a\"b@x"}), r#"{"email": "a\"b@x"}"#)]
+    #[case(r#"{"note": "{{ el.note }}"}"#, json!({"note": "line\nbreak \\ end"}), r#"{"note": "line\nbreak \\ end"}"#)]
+    #[case(r#"{"tags": {{ el.tags }}, "n": {{ el.n }}, "name": {{ el.name }}}"#, json!({"tags": ["a"], "n": 1, "name": "x"}), r#"{"tags": ["a"], "n": 1, "name": "x"}"#)]
+    #[case(r#"{"id": "{{ el.id }}-{{ el.n }}", "raw": {{ el.n | to_json }}}"#, json!({"id": "a", "n": 2}), r#"{"id": "a-2", "raw": 2}"#)]
+    #[case(r#"{"v": "\"{{ el.id }}\"", "w": {{ el.id }}}"#, json!({"id": "a"}), r#"{"v": "\"a\"", "w": "a"}"#)]
+    #[case(r#"{"v": "{{ el.id | append: '"}}' }}"}"#, json!({"id": "a"}), r#"{"v": "a\"}}"}"#)]
+    #[case(r#"{% if el.n %}{"n": {{- el.n -}}}{% endif %}"#, json!({"n": 3}), r#"{"n":3}"#)]
+    fn json_escape(
+        #[case] template: &str,
+        #[case] element: Value,
+        #[case] expected: &str,
+    ) -> Result<()> {
+        let tmpl =
+            LiquidJson::new(json!({"this":"{{ myval | each: tmpl, escape: 'json' | output }}"}));
+        let actual = tmpl.render(&json!({ "myval": [element], "tmpl": template }))?;
+        assert_eq!(actual, json!({ "this": [expected] }));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_escape() {
+        let tmpl = LiquidJson::new(json!("{{ myval | each: '{{ el }}', escape: 'xml' }}"));
+        assert!(tmpl.render(&json!({"myval": [1]})).is_err());
+    }
+}
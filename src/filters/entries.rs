@@ -0,0 +1,66 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Object, Value, ValueView};
+
+use super::{invalid_input, sorted_entries};
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "entries",
+    description = "Converts an object into an array of `{key, value}` objects, ordered by key.",
+    parsed(EntriesFilter)
+)]
+pub(crate) struct Entries;
+
+#[derive(Default, Display_filter)]
+#[name = "entries"]
+struct EntriesFilter;
+
+impl std::fmt::Debug for EntriesFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EntriesFilter").finish()
+    }
+}
+
+impl Filter for EntriesFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        let input = input
+            .as_object()
+            .ok_or_else(|| invalid_input("Object expected"))?;
+
+        let output: Vec<_> = sorted_entries(input)
+            .into_iter()
+            .map(|(k, v)| {
+                let mut entry = Object::new();
+                entry.insert("key".into(), Value::scalar(k.into_owned()));
+                entry.insert("value".into(), v.to_value());
+                Value::Object(entry)
+            })
+            .collect();
+        Ok(Value::array(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ myval | entries | output }}"}), json!({"myval": {"b": 1, "a": [2]}}), json!({"this":[{"key":"a","value":[2]},{"key":"b","value":1}]}))]
+    #[case(json!({"this":"{{ myval | entries | from_entries | output }}"}), json!({"myval": {"b": 1, "a": [2]}}), json!({"this":{"b": 1, "a": [2]}}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_argument, invalid_input};
+
+#[derive(Debug, FilterParameters)]
+struct FlattenArgs {
+    #[parameter(
+        description = "How many levels of nesting to flatten. Defaults to all of them.",
+        arg_type = "integer",
+        mode = "keyword"
+    )]
+    depth: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "flatten",
+    description = "Flattens nested arrays into a single array.",
+    parameters(FlattenArgs),
+    parsed(FlattenFilter)
+)]
+pub(crate) struct Flatten;
+
+impl ParseFilter for Flatten {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = FlattenArgs::from_args(arguments)?;
+
+        Ok(Box::new(FlattenFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "flatten"]
+struct FlattenFilter {
+    #[parameters]
+    args: FlattenArgs,
+}
+
+impl std::fmt::Debug for FlattenFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FlattenFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for FlattenFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let depth = match args.depth {
+            Some(depth) if depth < 0 => {
+                return Err(invalid_argument("depth", "Depth can't be negative"))
+            }
+            Some(depth) => depth,
+            None => i64::MAX,
+        };
+        let input = input
+            .as_array()
+            .ok_or_else(|| invalid_input("Array expected"))?;
+        let mut output = Vec::new();
+        for element in input.values() {
+            flatten(element, depth, &mut output);
+        }
+
+        Ok(Value::Array(output))
+    }
+}
+
+fn flatten(value: &dyn ValueView, depth: i64, output: &mut Vec<Value>) {
+    match value.as_array() {
+        Some(array) if depth > 0 => {
+            for element in array.values() {
+                flatten(element, depth - 1, output);
+            }
+        }
+        _ => output.push(value.to_value()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ arr | flatten | output }}"}), json!({"arr": [1, [2, [3, [4]]], []]}), json!({"this":[1,2,3,4]}))]
+    #[case(json!({"this":"{{ arr | flatten: depth: 1 | output }}"}), json!({"arr": [1, [2, [3, [4]]]]}), json!({"this":[1,2,[3,[4]]]}))]
+    #[case(json!({"this":"{{ arr | flatten: depth: 0 | output }}"}), json!({"arr": [1, [2]]}), json!({"this":[1,[2]]}))]
+    #[case(json!({"this":"{{ arr | flatten | output }}"}), json!({"arr": [{"a": [1]}, [{"b": 2}]]}), json!({"this":[{"a":[1]},{"b":2}]}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!("{{ arr | flatten }}"), json!({"arr": {"a": [1]}}))]
+    #[case(json!("{{ arr | flatten: depth: -1 }}"), json!({"arr": [[1]]}))]
+    fn invalid(#[case] template: Value, #[case] data: Value) {
+        let tmpl = LiquidJson::new(template);
+        assert!(tmpl.render(&data).is_err());
+    }
+}
@@ -0,0 +1,92 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Object, Value, ValueView};
+
+use super::invalid_input;
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "from_entries",
+    description = "Builds an object from an array of `{key, value}` objects or `[key, value]` pairs.",
+    parsed(FromEntriesFilter)
+)]
+pub(crate) struct FromEntries;
+
+#[derive(Default, Display_filter)]
+#[name = "from_entries"]
+struct FromEntriesFilter;
+
+impl std::fmt::Debug for FromEntriesFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FromEntriesFilter").finish()
+    }
+}
+
+impl Filter for FromEntriesFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        let input = input
+            .as_array()
+            .ok_or_else(|| invalid_input("Array expected"))?;
+
+        let output = input
+            .values()
+            .map(|entry| {
+                let pair = entry
+                    .as_object()
+                    .map(|entry| (entry.get("key"), entry.get("value")))
+                    .or_else(|| {
+                        entry
+                            .as_array()
+                            .filter(|pair| pair.size() == 2)
+                            .map(|pair| (pair.get(0), pair.get(1)))
+                    });
+                let (key, value) = match pair {
+                    Some((Some(key), value)) => (key, value),
+                    _ => {
+                        return Err(invalid_input(
+                            "Entries must be {key, value} objects or [key, value] pairs",
+                        ))
+                    }
+                };
+                Ok((
+                    key.to_kstr().into_owned(),
+                    value.map_or(Value::Nil, |v| v.to_value()),
+                ))
+            })
+            .collect::<Result<Object>>()?;
+        Ok(Value::Object(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ myval | from_entries | output }}"}), json!({"myval": [{"key":"a","value":1},{"key":"b"}]}), json!({"this":{"a":1,"b":null}}))]
+    #[case(json!({"this":"{{ myval | from_entries | output }}"}), json!({"myval": [["a",1],["b",[2]]]}), json!({"this":{"a":1,"b":[2]}}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!({"myval": [1]}))]
+    #[case(json!({"myval": [["a"]]}))]
+    #[case(json!({"myval": {"a": 1}}))]
+    fn invalid_entries(#[case] data: Value) {
+        let tmpl = LiquidJson::new(json!("{{ myval | from_entries }}"));
+        assert!(tmpl.render(&data).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Object;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_input, property};
+
+#[derive(Debug, FilterParameters)]
+struct GroupByArgs {
+    #[parameter(
+        description = "The property to group by, e.g. `status` or `owner.team`.",
+        arg_type = "str"
+    )]
+    property: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "group_by",
+    description = "Groups the elements of an array into an object of arrays, keyed by a property of each element.",
+    parameters(GroupByArgs),
+    parsed(GroupByFilter)
+)]
+pub(crate) struct GroupBy;
+
+impl ParseFilter for GroupBy {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = GroupByArgs::from_args(arguments)?;
+
+        Ok(Box::new(GroupByFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "group_by"]
+struct GroupByFilter {
+    #[parameters]
+    args: GroupByArgs,
+}
+
+impl std::fmt::Debug for GroupByFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GroupByFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for GroupByFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let input = input
+            .as_array()
+            .ok_or_else(|| invalid_input("Array expected"))?;
+        let mut groups = Object::new();
+        for element in input.values() {
+            let Some(key) = property(element, &args.property).filter(|k| !k.is_nil()) else {
+                continue;
+            };
+            let group = groups
+                .entry(key.to_kstr().into_owned())
+                .or_insert_with(|| Value::Array(Vec::new()));
+            if let Value::Array(group) = group {
+                group.push(element.to_value());
+            }
+        }
+
+        Ok(Value::Object(groups))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ orders | group_by: 'status' | output }}"}), json!({"orders": [{"id": 1, "status": "paid"}, {"id": 2, "status": "open"}, {"id": 3, "status": "paid"}, {"id": 4}]}), json!({"this":{"paid":[{"id": 1, "status": "paid"}, {"id": 3, "status": "paid"}],"open":[{"id": 2, "status": "open"}]}}))]
+    #[case(json!({"this":"{{ users | group_by: 'team.id' | output }}"}), json!({"users": [{"n": "a", "team": {"id": 1}}, {"n": "b", "team": {"id": 2}}, {"n": "c", "team": {"id": 1}}]}), json!({"this":{"1":[{"n": "a", "team": {"id": 1}}, {"n": "c", "team": {"id": 1}}],"2":[{"n": "b", "team": {"id": 2}}]}}))]
+    #[case(json!({"this":"{{ orders | group_by: 'status' | output }}"}), json!({"orders": []}), json!({"this":{}}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let tmpl = LiquidJson::new(json!("{{ orders | group_by: 'status' }}"));
+        assert!(tmpl.render(&json!({"orders": {"status": "paid"}})).is_err());
+    }
+}
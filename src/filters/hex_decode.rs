@@ -0,0 +1,100 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::decoded_value;
+
+#[derive(Debug, FilterParameters)]
+struct HexDecodeArgs {
+    #[parameter(
+        description = "How to return the decoded data: `string` (default) or `bytes`.",
+        arg_type = "str",
+        mode = "keyword"
+    )]
+    format: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "hex_decode",
+    description = "Decode a hex encoded string.",
+    parameters(HexDecodeArgs),
+    parsed(HexDecodeFilter)
+)]
+pub(crate) struct HexDecode;
+
+impl ParseFilter for HexDecode {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = HexDecodeArgs::from_args(arguments)?;
+
+        Ok(Box::new(HexDecodeFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "hex_decode"]
+struct HexDecodeFilter {
+    #[parameters]
+    args: HexDecodeArgs,
+}
+
+impl std::fmt::Debug for HexDecodeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HexDecodeFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for HexDecodeFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let decoded = hex::decode(input.to_kstr().as_bytes())
+            .map_err(|e| liquid_core::Error::with_msg(e.to_string()))?;
+
+        decoded_value(decoded, args.format.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"hex":"{{ '486921' | hex_decode }}"}), json!({}), json!({"hex": "Hi!"}))]
+    #[case(json!({"hex":"{{ '000FfF' | hex_decode: format: 'bytes' | output }}"}), json!({}), json!({"hex": [0, 15, 255]}))]
+    #[case(json!({"hex":"{{ '000fff' | hex_decode: format: 'bytes' | hex_encode }}"}), json!({}), json!({"hex": "000fff"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!("{{ 'abc' | hex_decode }}"))]
+    #[case(json!("{{ 'zz' | hex_decode }}"))]
+    #[case(json!("{{ 'ff' | hex_decode }}"))]
+    fn invalid(#[case] template: Value) {
+        let tmpl = LiquidJson::new(template);
+        assert!(tmpl.render(&json!({})).is_err());
+    }
+}
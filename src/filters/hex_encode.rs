@@ -0,0 +1,56 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+use super::input_bytes;
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "hex_encode",
+    description = "Encode a string or an array of bytes as lowercase hex.",
+    parsed(HexEncodeFilter)
+)]
+pub(crate) struct HexEncode;
+
+#[derive(Default, Display_filter)]
+#[name = "hex_encode"]
+struct HexEncodeFilter;
+
+impl std::fmt::Debug for HexEncodeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HexEncodeFilter").finish()
+    }
+}
+
+impl Filter for HexEncodeFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        let encoded = hex::encode(input_bytes(input)?);
+
+        Ok(Value::Scalar(encoded.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"hex":"{{ 'Hi!' | hex_encode }}"}), json!({}), json!({"hex": "486921"}))]
+    #[case(json!({"hex":"{{ bytes | hex_encode }}"}), json!({"bytes": [0, 15, 255]}), json!({"hex": "000fff"}))]
+    #[case(json!({"hex":"{{ 'héllo' | hex_encode | hex_decode }}"}), json!({}), json!({"hex": "héllo"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use hmac::{Hmac, Mac};
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+use sha2::Sha256;
+
+use super::{encode_digest, input_bytes};
+
+#[derive(Debug, FilterParameters)]
+struct HmacSha256Args {
+    #[parameter(description = "The secret key to sign with.", arg_type = "str")]
+    secret: Expression,
+    #[parameter(
+        description = "The encoding of the signature: `hex` (default) or `base64`.",
+        arg_type = "str",
+        mode = "keyword"
+    )]
+    encoding: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "hmac_sha256",
+    description = "Computes the HMAC-SHA256 signature of a string or an array of bytes.",
+    parameters(HmacSha256Args),
+    parsed(HmacSha256Filter)
+)]
+pub(crate) struct HmacSha256;
+
+impl ParseFilter for HmacSha256 {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = HmacSha256Args::from_args(arguments)?;
+
+        Ok(Box::new(HmacSha256Filter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "hmac_sha256"]
+struct HmacSha256Filter {
+    #[parameters]
+    args: HmacSha256Args,
+}
+
+impl std::fmt::Debug for HmacSha256Filter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HmacSha256Filter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for HmacSha256Filter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(args.secret.as_bytes())
+            .map_err(|e| liquid_core::Error::with_msg(e.to_string()))?;
+        mac.update(&input_bytes(input)?);
+        let signature = mac.finalize().into_bytes();
+        let encoded = encode_digest(&signature, args.encoding.as_deref())?;
+
+        Ok(Value::Scalar(encoded.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"sig":"{{ 'The quick brown fox jumps over the lazy dog' | hmac_sha256: 'key' }}"}), json!({}), json!({"sig": "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"}))]
+    #[case(json!({"sig":"{{ body | hmac_sha256: secret, encoding: 'base64' }}"}), json!({"body": "The quick brown fox jumps over the lazy dog", "secret": "key"}), json!({"sig": "97yD9DBThCSxMpjmqm+xQ+9NWaFJRhdZl0edvC0aPNg="}))]
+    #[case(json!({"sig":"{{ 'AAH/' | base64_decode: format: 'bytes' | hmac_sha256: 'key' }}"}), json!({}), json!({"sig": "d2f4ba1b05573946f42dac11f4d57168bb57b5ad729fda25b7464f4a98535b5a"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+use time::macros::format_description;
+use time::UtcOffset;
+use time_tz::{timezones, OffsetDateTimeExt};
+
+use super::{date_value, input_date, invalid_argument};
+
+#[derive(Debug, FilterParameters)]
+struct InTimezoneArgs {
+    #[parameter(
+        description = "An IANA time zone like `Europe/Paris`, `UTC`, or a fixed offset like `+02:00`.",
+        arg_type = "str"
+    )]
+    timezone: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "in_timezone",
+    description = "Converts a date to the same instant in another time zone.",
+    parameters(InTimezoneArgs),
+    parsed(InTimezoneFilter)
+)]
+pub(crate) struct InTimezone;
+
+impl ParseFilter for InTimezone {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = InTimezoneArgs::from_args(arguments)?;
+
+        Ok(Box::new(InTimezoneFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "in_timezone"]
+struct InTimezoneFilter {
+    #[parameters]
+    args: InTimezoneArgs,
+}
+
+impl std::fmt::Debug for InTimezoneFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InTimezoneFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for InTimezoneFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let date = input_date(input, runtime)?;
+        let name = args.timezone.trim();
+        let date = if name.eq_ignore_ascii_case("utc") || name == "Z" {
+            date.to_offset(UtcOffset::UTC)
+        } else if let Some(tz) = timezones::get_by_name(name) {
+            date.to_timezone(tz)
+        } else {
+            let offset = UtcOffset::parse(
+                name,
+                format_description!("[offset_hour sign:mandatory]:[offset_minute]"),
+            )
+            .map_err(|_| invalid_argument("timezone", "Unknown time zone"))?;
+            date.to_offset(offset)
+        };
+
+        Ok(date_value(date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"d":"{{ at | in_timezone: 'Europe/Paris' | to_iso8601 }}"}), json!({"at": "2024-07-01T12:00:00Z"}), json!({"d": "2024-07-01T14:00:00+02:00"}))]
+    #[case(json!({"d":"{{ at | in_timezone: 'Europe/Paris' | to_iso8601 }}"}), json!({"at": "2024-01-01T12:00:00Z"}), json!({"d": "2024-01-01T13:00:00+01:00"}))]
+    #[case(json!({"d":"{{ at | in_timezone: 'America/New_York' | date: '%Y-%m-%d %H:%M %z' }}"}), json!({"at": "2024-01-01T03:00:00Z"}), json!({"d": "2023-12-31 22:00 -0500"}))]
+    #[case(json!({"d":"{{ at | in_timezone: 'UTC' | to_iso8601 }}"}), json!({"at": "2024-01-01T03:00:00+05:30"}), json!({"d": "2023-12-31T21:30:00Z"}))]
+    #[case(json!({"d":"{{ at | in_timezone: '-03:30' | to_iso8601 }}"}), json!({"at": "2024-01-01T03:00:00Z"}), json!({"d": "2023-12-31T23:30:00-03:30"}))]
+    #[case(json!({"d":"{{ at | in_timezone: 'Asia/Tokyo' | to_unix | output }}"}), json!({"at": 1700000000}), json!({"d": 1700000000}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!("{{ '2024-02-03' | in_timezone: 'Mars/Olympus_Mons' }}"))]
+    #[case(json!("{{ '2024-02-03' | in_timezone: '0200' }}"))]
+    // Amsterdam was on local mean time (+00:19:32) in 1900, which RFC 3339 can't represent.
+    #[case(json!("{{ '1900-01-01T00:00:00Z' | in_timezone: 'Europe/Amsterdam' | output }}"))]
+    #[case(json!("{{ '1900-01-01T00:00:00Z' | in_timezone: 'Europe/Amsterdam' | to_json }}"))]
+    fn invalid(#[case] template: Value) {
+        let tmpl = LiquidJson::new(template);
+        assert!(tmpl.render(&json!({})).is_err());
+    }
+}
@@ -0,0 +1,105 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Object;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_input, property};
+
+#[derive(Debug, FilterParameters)]
+struct IndexByArgs {
+    #[parameter(
+        description = "The property to index by, e.g. `id` or `owner.email`.",
+        arg_type = "str"
+    )]
+    property: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "index_by",
+    description = "Builds an object of the elements of an array, keyed by a property of each element. Later elements replace earlier ones with the same key.",
+    parameters(IndexByArgs),
+    parsed(IndexByFilter)
+)]
+pub(crate) struct IndexBy;
+
+impl ParseFilter for IndexBy {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = IndexByArgs::from_args(arguments)?;
+
+        Ok(Box::new(IndexByFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "index_by"]
+struct IndexByFilter {
+    #[parameters]
+    args: IndexByArgs,
+}
+
+impl std::fmt::Debug for IndexByFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexByFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for IndexByFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let input = input
+            .as_array()
+            .ok_or_else(|| invalid_input("Array expected"))?;
+        let index: Object = input
+            .values()
+            .filter_map(|element| {
+                let key = property(element, &args.property).filter(|k| !k.is_nil())?;
+                Some((key.to_kstr().into_owned(), element.to_value()))
+            })
+            .collect();
+
+        Ok(Value::Object(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ users | index_by: 'id' | output }}"}), json!({"users": [{"id": "u1", "n": "a"}, {"id": "u2", "n": "b"}, {"n": "c"}]}), json!({"this":{"u1":{"id": "u1", "n": "a"},"u2":{"id": "u2", "n": "b"}}}))]
+    #[case(json!({"this":"{{ users | index_by: 'contact.email' | output }}"}), json!({"users": [{"contact": {"email": "a@x"}, "v": 1}, {"contact": {"email": "a@x"}, "v": 2}]}), json!({"this":{"a@x":{"contact": {"email": "a@x"}, "v": 2}}}))]
+    #[case(json!({"this":"{% assign by_id = users | index_by: 'id' %}{{ by_id.u2.n }}"}), json!({"users": [{"id": "u1", "n": "a"}, {"id": "u2", "n": "b"}]}), json!({"this":"b"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let tmpl = LiquidJson::new(json!("{{ users | index_by: 'id' }}"));
+        assert!(tmpl.render(&json!({"users": "u1"})).is_err());
+    }
+}
@@ -75,6 +75,8 @@ mod tests {
         "email": "jane@example.com"
       }
     ]}))]
+    #[case(json!({"this":"{{ myval | to_json | json | output }}"}), json!({"myval": {"a": [1, {"b": 2}]}}), json!({"this":{"a": [1, {"b": 2}]}}))]
+    #[case(json!({"recipients" : "{{ to | each: '{ \"email\": \"{{ el.email }}\", \"tags\": {{ el.tags }} }', escape: 'json' | json | output }}"}), json!({"to": [{"email": "\"John\" <john@example.com>", "tags": ["a\nb"]}]}), json!({"recipients": [{"email": "\"John\" <john@example.com>", "tags": ["a\nb"]}]}))]
     fn filters(
         #[case] template: Value,
         #[case] data: Value,
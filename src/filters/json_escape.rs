@@ -0,0 +1,64 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "json_escape",
+    description = "Escapes a string so it can be placed between the quotes of a JSON string.",
+    parsed(JsonEscapeFilter)
+)]
+pub(crate) struct JsonEscape;
+
+#[derive(Default, Display_filter)]
+#[name = "json_escape"]
+struct JsonEscapeFilter;
+
+impl std::fmt::Debug for JsonEscapeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonEscapeFilter").finish()
+    }
+}
+
+impl Filter for JsonEscapeFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        let quoted = serde_json::to_string(input.to_kstr().as_str())
+            .map_err(|e| liquid_core::Error::with_msg(e.to_string()))?;
+
+        Ok(Value::scalar(quoted[1..quoted.len() - 1].to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case("plain", "plain")]
+    #[case(r#"say "hi""#, r#"say \"hi\""#)]
+    #[case("C:\\temp", "C:\\\\temp")]
+    #[case("line\nbreak\ttab", "line\\nbreak\\ttab")]
+    #[case("bell\u{7}", "bell\\u0007")]
+    #[case("é/✓", "é/✓")]
+    fn filters(#[case] input: &str, #[case] expected: &str) -> Result<()> {
+        let tmpl = LiquidJson::new(json!("{{ input | json_escape }}"));
+        let actual = tmpl.render(&json!({ "input": input }))?;
+        assert_eq!(actual, Value::String(expected.to_owned()));
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> Result<()> {
+        let input = "quote \" backslash \\ newline \n";
+        let tmpl = LiquidJson::new(json!("{\"v\": \"{{ input | json_escape }}\"}"));
+        let actual = tmpl.render(&json!({ "input": input }))?;
+        let parsed: Value = serde_json::from_str(actual.as_str().unwrap_or_default())?;
+        assert_eq!(parsed, json!({ "v": input }));
+        Ok(())
+    }
+}
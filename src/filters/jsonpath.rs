@@ -0,0 +1,104 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+use serde_json_path::JsonPath as Path;
+
+use super::invalid_input;
+use crate::{to_json_value, to_liquid_value};
+
+#[derive(Debug, FilterParameters)]
+struct JsonPathArgs {
+    #[parameter(description = "The JSONPath query to apply.", arg_type = "str")]
+    query: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "jsonpath",
+    description = "Queries a value with a JSONPath expression, returning an array of every match.",
+    parameters(JsonPathArgs),
+    parsed(JsonPathFilter)
+)]
+pub(crate) struct JsonPath;
+
+impl ParseFilter for JsonPath {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = JsonPathArgs::from_args(arguments)?;
+
+        Ok(Box::new(JsonPathFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "jsonpath"]
+struct JsonPathFilter {
+    #[parameters]
+    args: JsonPathArgs,
+}
+
+impl std::fmt::Debug for JsonPathFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonPathFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for JsonPathFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let path = Path::parse(&args.query)
+            .map_err(|e| invalid_input(format!("invalid JSONPath query: {}", e)))?;
+        let input = to_json_value(input.to_value())?;
+
+        let output: Vec<_> = path
+            .query(&input)
+            .all()
+            .into_iter()
+            .map(|v| to_liquid_value(v).map_err(|e| liquid_core::Error::with_msg(e.to_string())))
+            .collect::<Result<_, _>>()?;
+        Ok(Value::array(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ res | jsonpath: '$.items[?@.active == true].id' | output }}"}), json!({"res": {"items": [{"id": 1, "active": true}, {"id": 2, "active": false}, {"id": 3, "active": true}]}}), json!({"this":[1,3]}))]
+    #[case(json!({"this":"{{ res | jsonpath: '$..name' | output }}"}), json!({"res": {"a": {"name": "x"}, "b": [{"name": "y"}]}}), json!({"this":["x","y"]}))]
+    #[case(json!({"this":"{{ res | jsonpath: '$.missing' | output }}"}), json!({"res": {"a": 1}}), json!({"this":[]}))]
+    #[case(json!({"this":"{{ empty | jsonpath: '$' | output }}"}), json!({}), json!({"this":[""]}))]
+    #[case(json!({"this":"{{ res | jsonpath: '$.a' | first }}"}), json!({"res": {"a": 1}}), json!({"this":"1"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_query() {
+        let tmpl = LiquidJson::new(json!("{{ res | jsonpath: 'items' }}"));
+        assert!(tmpl.render(&json!({"res": {}})).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+use super::Case;
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "kebab_case",
+    description = "Converts a string to kebab-case.",
+    parsed(KebabCaseFilter)
+)]
+pub(crate) struct KebabCase;
+
+#[derive(Default, Display_filter)]
+#[name = "kebab_case"]
+struct KebabCaseFilter;
+
+impl std::fmt::Debug for KebabCaseFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KebabCaseFilter").finish()
+    }
+}
+
+impl Filter for KebabCaseFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        Ok(Value::scalar(Case::Kebab.convert(input.to_kstr().as_str())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case("user_id", "user-id")]
+    #[case("userId", "user-id")]
+    #[case("UserID", "user-id")]
+    #[case("HTTPServerError", "http-server-error")]
+    #[case("first-name", "first-name")]
+    #[case("SCREAMING_SNAKE", "screaming-snake")]
+    #[case("version2Name", "version2-name")]
+    #[case(" leading  spaces ", "leading-spaces")]
+    #[case("", "")]
+    fn filters(#[case] input: &str, #[case] expected: &str) -> Result<()> {
+        let tmpl = LiquidJson::new(json!("{{ input | kebab_case }}"));
+        let actual = tmpl.render(&json!({ "input": input }))?;
+        assert_eq!(actual, Value::String(expected.to_owned()));
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_input, sorted_entries};
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "keys",
+    description = "Returns the keys of an object in sorted order.",
+    parsed(KeysFilter)
+)]
+pub(crate) struct Keys;
+
+#[derive(Default, Display_filter)]
+#[name = "keys"]
+struct KeysFilter;
+
+impl std::fmt::Debug for KeysFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeysFilter").finish()
+    }
+}
+
+impl Filter for KeysFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        let input = input
+            .as_object()
+            .ok_or_else(|| invalid_input("Object expected"))?;
+
+        let output: Vec<_> = sorted_entries(input)
+            .into_iter()
+            .map(|(k, _)| Value::scalar(k.into_owned()))
+            .collect();
+        Ok(Value::array(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ myval | keys | output }}"}), json!({"myval": {"b": 1, "a": {"c": 2}}}), json!({"this":["a","b"]}))]
+    #[case(json!({"this":"{{ myval | keys | output }}"}), json!({"myval": {}}), json!({"this":[]}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use std::cmp::Ordering;
+
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::{present_values, Number};
+
+#[derive(Debug, FilterParameters)]
+struct MaxArgs {
+    #[parameter(
+        description = "The property of each element to compare, e.g. `price` or `totals.net`.",
+        arg_type = "str"
+    )]
+    property: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "max",
+    description = "Finds the largest number in an array, or of a property of each element.",
+    parameters(MaxArgs),
+    parsed(MaxFilter)
+)]
+pub(crate) struct Max;
+
+impl ParseFilter for Max {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = MaxArgs::from_args(arguments)?;
+
+        Ok(Box::new(MaxFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "max"]
+struct MaxFilter {
+    #[parameters]
+    args: MaxArgs,
+}
+
+impl std::fmt::Debug for MaxFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MaxFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for MaxFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let mut max: Option<Number> = None;
+        for value in present_values(input, args.property.as_deref())? {
+            let value = Number::from_value(value)?;
+            if max.is_none_or(|max| value.to_f64().total_cmp(&max.to_f64()) == Ordering::Greater) {
+                max = Some(value);
+            }
+        }
+
+        Ok(max.map_or(Value::Nil, Number::into_value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ nums | max | output }}"}), json!({"nums": [3, 1.5, 2]}), json!({"this":3}))]
+    #[case(json!({"this":"{{ nums | max | output }}"}), json!({"nums": [3, -1, "4.5"]}), json!({"this":4.5}))]
+    #[case(json!({"this":"{{ nums | max | output }}"}), json!({"nums": [null]}), json!({"this":null}))]
+    #[case(json!({"this":"{{ items | max: 'stats.score' | output }}"}), json!({"items": [{"stats": {"score": 10}}, {"stats": {"score": 50}}]}), json!({"this":50}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let tmpl = LiquidJson::new(json!("{{ nums | max }}"));
+        assert!(tmpl.render(&json!({"nums": {"a": 1}})).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::digest_value;
+
+#[derive(Debug, FilterParameters)]
+struct Md5Args {
+    #[parameter(
+        description = "The encoding of the digest: `hex` (default) or `base64`.",
+        arg_type = "str",
+        mode = "keyword"
+    )]
+    encoding: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "md5",
+    description = "Computes the MD5 digest of a string or an array of bytes.",
+    parameters(Md5Args),
+    parsed(Md5Filter)
+)]
+pub(crate) struct Md5;
+
+impl ParseFilter for Md5 {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = Md5Args::from_args(arguments)?;
+
+        Ok(Box::new(Md5Filter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "md5"]
+struct Md5Filter {
+    #[parameters]
+    args: Md5Args,
+}
+
+impl std::fmt::Debug for Md5Filter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Md5Filter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for Md5Filter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        digest_value::<md5::Md5>(input, args.encoding.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"hash":"{{ 'hello' | md5 }}"}), json!({}), json!({"hash": "5d41402abc4b2a76b9719d911017c592"}))]
+    #[case(json!({"hash":"{{ 'hello' | md5: encoding: 'base64' }}"}), json!({}), json!({"hash": "XUFAKrxLKna5cZ2REBfFkg=="}))]
+    #[case(json!({"hash":"{{ 'AAH/' | base64_decode: format: 'bytes' | md5 }}"}), json!({}), json!({"hash": "ffbb8cd5a232b7d906904533e9609f48"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
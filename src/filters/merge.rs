@@ -0,0 +1,111 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_argument, invalid_input};
+
+#[derive(Debug, FilterParameters)]
+struct MergeArgs {
+    #[parameter(
+        description = "The object whose keys replace those of the input. Nil leaves the input unchanged.",
+        arg_type = "any"
+    )]
+    other: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "merge",
+    description = "Shallowly merges another object into an object, replacing existing keys.",
+    parameters(MergeArgs),
+    parsed(MergeFilter)
+)]
+pub(crate) struct Merge;
+
+impl ParseFilter for Merge {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = MergeArgs::from_args(arguments)?;
+
+        Ok(Box::new(MergeFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "merge"]
+struct MergeFilter {
+    #[parameters]
+    args: MergeArgs,
+}
+
+impl std::fmt::Debug for MergeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MergeFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for MergeFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let mut output = input
+            .as_object()
+            .ok_or_else(|| invalid_input("Object expected"))?
+            .to_value();
+        if args.other.is_nil() {
+            return Ok(output);
+        }
+        let other = args
+            .other
+            .as_object()
+            .ok_or_else(|| invalid_argument("other", "Object expected"))?;
+
+        if let Value::Object(output) = &mut output {
+            for (k, v) in other.iter() {
+                output.insert(k.into_owned(), v.to_value());
+            }
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ defaults | merge: config | output }}"}), json!({"defaults": {"a": 1, "b": {"c": 2, "d": 3}}, "config": {"b": {"c": 4}, "e": 5}}), json!({"this":{"a": 1, "b": {"c": 4}, "e": 5}}))]
+    #[case(json!({"this":"{{ defaults | merge: nil | output }}"}), json!({"defaults": {"a": 1}}), json!({"this":{"a": 1}}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!({"defaults": [1], "config": {}}))]
+    #[case(json!({"defaults": {}, "config": [1]}))]
+    fn invalid_merge(#[case] data: Value) {
+        let tmpl = LiquidJson::new(json!("{{ defaults | merge: config }}"));
+        assert!(tmpl.render(&data).is_err());
+    }
+}
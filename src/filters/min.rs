@@ -0,0 +1,104 @@
+use std::cmp::Ordering;
+
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::{present_values, Number};
+
+#[derive(Debug, FilterParameters)]
+struct MinArgs {
+    #[parameter(
+        description = "The property of each element to compare, e.g. `price` or `totals.net`.",
+        arg_type = "str"
+    )]
+    property: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "min",
+    description = "Finds the smallest number in an array, or of a property of each element.",
+    parameters(MinArgs),
+    parsed(MinFilter)
+)]
+pub(crate) struct Min;
+
+impl ParseFilter for Min {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = MinArgs::from_args(arguments)?;
+
+        Ok(Box::new(MinFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "min"]
+struct MinFilter {
+    #[parameters]
+    args: MinArgs,
+}
+
+impl std::fmt::Debug for MinFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MinFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for MinFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let mut min: Option<Number> = None;
+        for value in present_values(input, args.property.as_deref())? {
+            let value = Number::from_value(value)?;
+            if min.is_none_or(|min| value.to_f64().total_cmp(&min.to_f64()) == Ordering::Less) {
+                min = Some(value);
+            }
+        }
+
+        Ok(min.map_or(Value::Nil, Number::into_value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ nums | min | output }}"}), json!({"nums": [3, 1.5, 2]}), json!({"this":1.5}))]
+    #[case(json!({"this":"{{ nums | min | output }}"}), json!({"nums": [3, -1, "2"]}), json!({"this":-1}))]
+    #[case(json!({"this":"{{ nums | min | output }}"}), json!({"nums": []}), json!({"this":null}))]
+    #[case(json!({"this":"{{ items | min: 'price' | output }}"}), json!({"items": [{"price": 10}, {"price": 5}, {}]}), json!({"this":5}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let tmpl = LiquidJson::new(json!("{{ nums | min }}"));
+        assert!(tmpl.render(&json!({"nums": [1, true]})).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_input, key_list, variadic_args};
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "omit",
+    description = "Removes the given keys from an object. Keys can be passed as strings or arrays of strings.",
+    parsed(OmitFilter)
+)]
+pub(crate) struct Omit;
+
+impl ParseFilter for Omit {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let keys = variadic_args(arguments)?;
+
+        Ok(Box::new(OmitFilter { keys }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(Display_filter)]
+#[name = "omit"]
+struct OmitFilter {
+    keys: Vec<Expression>,
+}
+
+impl std::fmt::Debug for OmitFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OmitFilter")
+            .field("keys", &self.keys)
+            .finish()
+    }
+}
+
+impl Filter for OmitFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let keys = self
+            .keys
+            .iter()
+            .map(|k| k.evaluate(runtime))
+            .collect::<Result<Vec<_>>>()?;
+        let mut output = input
+            .as_object()
+            .ok_or_else(|| invalid_input("Object expected"))?
+            .to_value();
+
+        if let Value::Object(output) = &mut output {
+            for key in key_list(keys.iter().map(|k| k.as_view())) {
+                output.remove(key.as_str());
+            }
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ myval | omit: 'a', 'c' | output }}"}), json!({"myval": {"a": 1, "b": 2, "c": {"d": 3}}}), json!({"this":{"b": 2}}))]
+    #[case(json!({"this":"{{ myval | omit: fields, 'missing' | output }}"}), json!({"myval": {"a": 1, "b": 2}, "fields": ["b"]}), json!({"this":{"a": 1}}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
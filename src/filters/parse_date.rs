@@ -0,0 +1,80 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+use super::{date_value, input_date};
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "parse_date",
+    description = "Parses a date from an ISO 8601 or Liquid formatted string, a unix timestamp, or `now`.",
+    parsed(ParseDateFilter)
+)]
+pub(crate) struct ParseDate;
+
+#[derive(Default, Display_filter)]
+#[name = "parse_date"]
+struct ParseDateFilter;
+
+impl std::fmt::Debug for ParseDateFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParseDateFilter").finish()
+    }
+}
+
+impl Filter for ParseDateFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let date = input_date(input, runtime)?;
+
+        Ok(date_value(date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use crate::{Environment, LiquidJson};
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"d":"{{ '2024-02-03T04:05:06+02:00' | parse_date | output }}"}), json!({"d": "2024-02-03T04:05:06+02:00"}))]
+    #[case(json!({"d":"{{ '2024-02-03T04:05:06.5Z' | parse_date | output }}"}), json!({"d": "2024-02-03T04:05:06.5Z"}))]
+    #[case(json!({"d":"{{ '2024-02-03T04:05:06' | parse_date | output }}"}), json!({"d": "2024-02-03T04:05:06Z"}))]
+    #[case(json!({"d":"{{ '2024-02-03' | parse_date | output }}"}), json!({"d": "2024-02-03T00:00:00Z"}))]
+    #[case(json!({"d":"{{ '2024-02-03 04:05:06 +0100' | parse_date | output }}"}), json!({"d": "2024-02-03T04:05:06+01:00"}))]
+    #[case(json!({"d":"{{ 86400 | parse_date | output }}"}), json!({"d": "1970-01-02T00:00:00Z"}))]
+    #[case(json!({"d":"{{ 'now' | parse_date | output }}"}), json!({"d": "2023-11-14T22:13:20Z"}))]
+    #[case(json!({"d":"{{ '2024-02-03' | parse_date | date: '%B %-d, %Y' }}"}), json!({"d": "February 3, 2024"}))]
+    fn filters(#[case] template: Value, #[case] expected: Value) -> Result<()> {
+        let env = Environment::new()
+            .with_now(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render_with(&json!({}), &env)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!("{{ 'yesterday-ish' | parse_date }}"))]
+    #[case(json!("{{ true | parse_date }}"))]
+    fn invalid(#[case] template: Value) {
+        let tmpl = LiquidJson::new(template);
+        assert!(tmpl.render(&json!({})).is_err());
+    }
+
+    #[test]
+    fn now_is_stable_within_a_render() -> Result<()> {
+        let tmpl = LiquidJson::new(json!([
+            "{{ 'now' | to_iso8601 }}",
+            "{{ 'now' | to_iso8601 }}"
+        ]));
+        let actual = tmpl.render(&json!({}))?;
+        assert_eq!(actual[0], actual[1]);
+        Ok(())
+    }
+}
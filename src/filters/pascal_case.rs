@@ -0,0 +1,58 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+use super::Case;
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "pascal_case",
+    description = "Converts a string to PascalCase.",
+    parsed(PascalCaseFilter)
+)]
+pub(crate) struct PascalCase;
+
+#[derive(Default, Display_filter)]
+#[name = "pascal_case"]
+struct PascalCaseFilter;
+
+impl std::fmt::Debug for PascalCaseFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PascalCaseFilter").finish()
+    }
+}
+
+impl Filter for PascalCaseFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        Ok(Value::scalar(
+            Case::Pascal.convert(input.to_kstr().as_str()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case("user_id", "UserId")]
+    #[case("userId", "UserId")]
+    #[case("UserID", "UserId")]
+    #[case("HTTPServerError", "HttpServerError")]
+    #[case("first-name", "FirstName")]
+    #[case("SCREAMING_SNAKE", "ScreamingSnake")]
+    #[case("version2Name", "Version2Name")]
+    #[case(" leading  spaces ", "LeadingSpaces")]
+    #[case("", "")]
+    fn filters(#[case] input: &str, #[case] expected: &str) -> Result<()> {
+        let tmpl = LiquidJson::new(json!("{{ input | pascal_case }}"));
+        let actual = tmpl.render(&json!({ "input": input }))?;
+        assert_eq!(actual, Value::String(expected.to_owned()));
+        Ok(())
+    }
+}
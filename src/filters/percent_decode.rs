@@ -0,0 +1,64 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+use percent_encoding::percent_decode_str;
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "percent_decode",
+    description = "Decode a percent-encoded string. Unlike `url_decode`, `+` is left as is.",
+    parsed(PercentDecodeFilter)
+)]
+pub(crate) struct PercentDecode;
+
+#[derive(Default, Display_filter)]
+#[name = "percent_decode"]
+struct PercentDecodeFilter;
+
+impl std::fmt::Debug for PercentDecodeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PercentDecodeFilter").finish()
+    }
+}
+
+impl Filter for PercentDecodeFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        let input = input.to_kstr();
+        let decoded = percent_decode_str(input.as_str())
+            .decode_utf8()
+            .map_err(|e| liquid_core::Error::with_msg(e.to_string()))?
+            .into_owned();
+
+        Ok(Value::Scalar(decoded.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"q":"{{ 'a%20b+c%2F%C3%A9' | percent_decode }}"}), json!({}), json!({"q": "a b+c/é"}))]
+    #[case(json!({"q":"{{ '100%' | percent_decode }}"}), json!({}), json!({"q": "100%"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let tmpl = LiquidJson::new(json!("{{ '%FF' | percent_decode }}"));
+        assert!(tmpl.render(&json!({})).is_err());
+    }
+}
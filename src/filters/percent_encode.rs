@@ -0,0 +1,62 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+/// Everything but the RFC 3986 unreserved characters.
+const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "percent_encode",
+    description = "Percent-encode a string as an RFC 3986 URI component, leaving only unreserved characters as is.",
+    parsed(PercentEncodeFilter)
+)]
+pub(crate) struct PercentEncode;
+
+#[derive(Default, Display_filter)]
+#[name = "percent_encode"]
+struct PercentEncodeFilter;
+
+impl std::fmt::Debug for PercentEncodeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PercentEncodeFilter").finish()
+    }
+}
+
+impl Filter for PercentEncodeFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        let input = input.to_kstr();
+        let encoded = utf8_percent_encode(input.as_str(), COMPONENT).to_string();
+
+        Ok(Value::Scalar(encoded.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"q":"{{ 'a b&c=d/é~-._' | percent_encode }}"}), json!({}), json!({"q": "a%20b%26c%3Dd%2F%C3%A9~-._"}))]
+    #[case(json!({"q":"{{ query | percent_encode | percent_decode }}"}), json!({"query": "100% + \"quoted\" ?x=1#frag"}), json!({"q": "100% + \"quoted\" ?x=1#frag"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Object, Value, ValueView};
+
+use super::{invalid_input, key_list, variadic_args};
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "pick",
+    description = "Keeps only the given keys of an object. Keys can be passed as strings or arrays of strings.",
+    parsed(PickFilter)
+)]
+pub(crate) struct Pick;
+
+impl ParseFilter for Pick {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let keys = variadic_args(arguments)?;
+
+        Ok(Box::new(PickFilter { keys }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(Display_filter)]
+#[name = "pick"]
+struct PickFilter {
+    keys: Vec<Expression>,
+}
+
+impl std::fmt::Debug for PickFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PickFilter")
+            .field("keys", &self.keys)
+            .finish()
+    }
+}
+
+impl Filter for PickFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let keys = self
+            .keys
+            .iter()
+            .map(|k| k.evaluate(runtime))
+            .collect::<Result<Vec<_>>>()?;
+        let input = input
+            .as_object()
+            .ok_or_else(|| invalid_input("Object expected"))?;
+
+        let output: Object = key_list(keys.iter().map(|k| k.as_view()))
+            .into_iter()
+            .filter_map(|k| {
+                let value = input.get(k.as_str())?.to_value();
+                Some((k, value))
+            })
+            .collect();
+        Ok(Value::Object(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ myval | pick: 'a', 'c' | output }}"}), json!({"myval": {"a": 1, "b": 2, "c": {"d": 3}}}), json!({"this":{"a": 1, "c": {"d": 3}}}))]
+    #[case(json!({"this":"{{ myval | pick: fields, 'missing' | output }}"}), json!({"myval": {"a": 1, "b": 2}, "fields": ["b"]}), json!({"this":{"b": 2}}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use crate::{to_json_value, to_liquid_value};
+
+#[derive(Debug, FilterParameters)]
+struct PointerArgs {
+    #[parameter(description = "The JSON Pointer to resolve.", arg_type = "str")]
+    pointer: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "pointer",
+    description = "Resolves a JSON Pointer against a value, returning nil when nothing is found.",
+    parameters(PointerArgs),
+    parsed(PointerFilter)
+)]
+pub(crate) struct Pointer;
+
+impl ParseFilter for Pointer {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = PointerArgs::from_args(arguments)?;
+
+        Ok(Box::new(PointerFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "pointer"]
+struct PointerFilter {
+    #[parameters]
+    args: PointerArgs,
+}
+
+impl std::fmt::Debug for PointerFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PointerFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for PointerFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let input = to_json_value(input.to_value())?;
+
+        input
+            .pointer(&args.pointer)
+            .map_or(Ok(Value::Nil), |value| {
+                to_liquid_value(value).map_err(|e| liquid_core::Error::with_msg(e.to_string()))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ res | pointer: '/a/0/b' | output }}"}), json!({"res": {"a": [{"b": {"c": 1}}]}}), json!({"this":{"c": 1}}))]
+    #[case(json!({"this":"{{ res | pointer: '/a~1b' | output }}"}), json!({"res": {"a/b": 5}}), json!({"this":5}))]
+    #[case(json!({"this":"{{ blank | pointer: '' | output }}"}), json!({}), json!({"this":""}))]
+    #[case(json!({"this":"{{ res | pointer: '' | output }}"}), json!({"res": [1]}), json!({"this":[1]}))]
+    #[case(json!({"this":"{{ res | pointer: '/missing' | default: 'none' }}"}), json!({"res": {}}), json!({"this":"none"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+use rand::Rng;
+
+use super::invalid_input;
+use crate::environment::RandomRegister;
+
+#[derive(Debug, FilterParameters)]
+struct RandomIntArgs {
+    #[parameter(description = "The inclusive upper bound.", arg_type = "integer")]
+    max: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "random_int",
+    description = "Generates a random integer between the input and the argument, inclusive.",
+    parameters(RandomIntArgs),
+    parsed(RandomIntFilter)
+)]
+pub(crate) struct RandomInt;
+
+impl ParseFilter for RandomInt {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = RandomIntArgs::from_args(arguments)?;
+
+        Ok(Box::new(RandomIntFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "random_int"]
+struct RandomIntFilter {
+    #[parameters]
+    args: RandomIntArgs,
+}
+
+impl std::fmt::Debug for RandomIntFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RandomIntFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for RandomIntFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let min = input
+            .as_scalar()
+            .and_then(|v| v.to_integer())
+            .ok_or_else(|| invalid_input("Whole number expected"))?;
+        if min > args.max {
+            return Err(invalid_input("Lower bound is greater than the upper bound"));
+        }
+        let value = runtime
+            .registers()
+            .get_mut::<RandomRegister>()
+            .rng()
+            .gen_range(min..=args.max);
+
+        Ok(Value::scalar(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Environment, LiquidJson};
+    use serde_json::json;
+
+    use anyhow::Result;
+
+    #[test]
+    fn in_range() -> Result<()> {
+        let tmpl = LiquidJson::new(json!("{{ 1 | random_int: 3 | output }}"));
+        for _ in 0..20 {
+            let value = tmpl.render(&json!({}))?;
+            assert!((1..=3).contains(&value.as_i64().unwrap()));
+        }
+        let tmpl = LiquidJson::new(json!("{{ 5 | random_int: 5 | output }}"));
+        assert_eq!(tmpl.render(&json!({}))?, json!(5));
+        Ok(())
+    }
+
+    #[test]
+    fn seeded() -> Result<()> {
+        let tmpl = LiquidJson::new(json!([
+            "{{ 0 | random_int: 1000000 }}",
+            "{{ 0 | random_int: 1000000 }}"
+        ]));
+        let env = Environment::new().with_seed(7);
+        assert_eq!(
+            tmpl.render_with(&json!({}), &env)?,
+            tmpl.render_with(&json!({}), &env)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_range() {
+        let tmpl = LiquidJson::new(json!("{{ 3 | random_int: 1 }}"));
+        assert!(tmpl.render(&json!({})).is_err());
+    }
+}
@@ -0,0 +1,144 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_argument, invalid_input};
+
+/// The most elements a range can produce, so a typo can't exhaust memory.
+const MAX_RANGE_LEN: i64 = 100_000;
+
+#[derive(Debug, FilterParameters)]
+struct RangeArgs {
+    #[parameter(description = "The inclusive end of the range.", arg_type = "integer")]
+    end: Expression,
+    #[parameter(
+        description = "The difference between consecutive numbers. Defaults to 1, or -1 when the end is below the start.",
+        arg_type = "integer",
+        mode = "keyword"
+    )]
+    step: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "range",
+    description = "Generates an array of whole numbers from the input to the argument, inclusive.",
+    parameters(RangeArgs),
+    parsed(RangeFilter)
+)]
+pub(crate) struct Range;
+
+impl ParseFilter for Range {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = RangeArgs::from_args(arguments)?;
+
+        Ok(Box::new(RangeFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "range"]
+struct RangeFilter {
+    #[parameters]
+    args: RangeArgs,
+}
+
+impl std::fmt::Debug for RangeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RangeFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for RangeFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let start = input
+            .as_scalar()
+            .and_then(|v| v.to_integer())
+            .ok_or_else(|| invalid_input("Whole number expected"))?;
+        let end = args.end;
+        let step = args.step.unwrap_or(if end < start { -1 } else { 1 });
+        if step == 0 {
+            return Err(invalid_argument("step", "Step can't be zero"));
+        }
+        let distance = i128::from(end) - i128::from(start);
+        // A step that points away from the end never reaches it, not even once.
+        if distance != 0 && distance.signum() != i128::from(step).signum() {
+            return Ok(Value::array(Vec::new()));
+        }
+        let len = distance / i128::from(step) + 1;
+        if len > i128::from(MAX_RANGE_LEN) {
+            return Err(invalid_input("Range is too long"));
+        }
+
+        let output = std::iter::successors(Some(start), |n| n.checked_add(step))
+            .take(len as usize)
+            .map(Value::scalar);
+        Ok(Value::array(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ 1 | range: 5 | output }}"}), json!([1,2,3,4,5]))]
+    #[case(json!({"this":"{{ 0 | range: 10, step: 4 | output }}"}), json!([0,4,8]))]
+    #[case(json!({"this":"{{ 3 | range: 1 | output }}"}), json!([3,2,1]))]
+    #[case(json!({"this":"{{ 1 | range: 3, step: -1 | output }}"}), json!([]))]
+    #[case(json!({"this":"{{ 1 | range: 3, step: -5 | output }}"}), json!([]))]
+    #[case(json!({"this":"{{ 1 | range: 0, step: 5 | output }}"}), json!([]))]
+    #[case(json!({"this":"{{ 3 | range: 1, step: -5 | output }}"}), json!([3]))]
+    #[case(json!({"this":"{{ 2 | range: 2, step: -3 | output }}"}), json!([2]))]
+    #[case(json!({"this":"{{ 2 | range: 2 | output }}"}), json!([2]))]
+    #[case(json!({"this":"{{ 1 | range: 3 | each: 'item-{{ el }}' | output }}"}), json!(["item-1","item-2","item-3"]))]
+    fn filters(#[case] template: Value, #[case] expected: Value) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&json!({}))?;
+        assert_eq!(actual, json!({ "this": expected }));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!("{{ 1 | range: 5, step: 0 }}"))]
+    #[case(json!("{{ 'a' | range: 5 }}"))]
+    #[case(json!("{{ 0 | range: 1000000 }}"))]
+    fn invalid(#[case] template: Value) {
+        let tmpl = LiquidJson::new(template);
+        assert!(tmpl.render(&json!({})).is_err());
+    }
+
+    #[test]
+    fn extremes() -> Result<()> {
+        let tmpl = LiquidJson::new(json!("{{ a | range: b, step: b | output }}"));
+        let actual = tmpl.render(
+            &json!({"a": -9_000_000_000_000_000_000_i64, "b": 9_000_000_000_000_000_000_i64}),
+        )?;
+        assert_eq!(
+            actual,
+            json!([
+                -9_000_000_000_000_000_000_i64,
+                0,
+                9_000_000_000_000_000_000_i64
+            ])
+        );
+        Ok(())
+    }
+}
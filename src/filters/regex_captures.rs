@@ -0,0 +1,113 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Object;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::cached_regex;
+
+#[derive(Debug, FilterParameters)]
+struct RegexCapturesArgs {
+    #[parameter(
+        description = "The regular expression to match, with named capture groups.",
+        arg_type = "str"
+    )]
+    pattern: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "regex_captures",
+    description = "Extracts the named capture groups of the first match of a regular expression into an object.",
+    parameters(RegexCapturesArgs),
+    parsed(RegexCapturesFilter)
+)]
+pub(crate) struct RegexCaptures;
+
+impl ParseFilter for RegexCaptures {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = RegexCapturesArgs::from_args(arguments)?;
+
+        Ok(Box::new(RegexCapturesFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "regex_captures"]
+struct RegexCapturesFilter {
+    #[parameters]
+    args: RegexCapturesArgs,
+}
+
+impl std::fmt::Debug for RegexCapturesFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegexCapturesFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for RegexCapturesFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let regex = cached_regex(&args.pattern)?;
+        let input = input.to_kstr();
+        let Some(captures) = regex.captures(input.as_str()) else {
+            return Ok(Value::Nil);
+        };
+        // Groups that didn't take part in the match are nil rather than missing, so the object
+        // always has the same keys.
+        let output: Object = regex
+            .capture_names()
+            .flatten()
+            .map(|name| {
+                let value = captures
+                    .name(name)
+                    .map_or(Value::Nil, |m| Value::scalar(m.as_str().to_owned()));
+                (name.to_owned().into(), value)
+            })
+            .collect();
+
+        Ok(Value::Object(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ ref | regex_captures: '^(?P<owner>[^/]+)/(?P<repo>[^@]+)@(?P<version>.+)$' | output }}"}), json!({"ref": "acme/widgets@v1.2"}), json!({"this":{"owner":"acme","repo":"widgets","version":"v1.2"}}))]
+    #[case(json!({"this":"{{ ref | regex_captures: '(?P<major>\\d+)(\\.(?P<minor>\\d+))?' | output }}"}), json!({"ref": "v3"}), json!({"this":{"major":"3","minor":null}}))]
+    #[case(json!({"this":"{{ ref | regex_captures: '(?P<major>\\d+)' | output }}"}), json!({"ref": "latest"}), json!({"this":null}))]
+    #[case(json!({"this":"{% assign v = ref | regex_captures: 'v(?P<major>\\d+)' %}{{ v.major }}"}), json!({"ref": "v3.1"}), json!({"this":"3"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let tmpl = LiquidJson::new(json!("{{ 'abc' | regex_captures: '(?P<x' }}"));
+        assert!(tmpl.render(&json!({})).is_err());
+    }
+}
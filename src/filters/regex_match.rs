@@ -0,0 +1,96 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::cached_regex;
+
+#[derive(Debug, FilterParameters)]
+struct RegexMatchArgs {
+    #[parameter(
+        description = "The regular expression to search for.",
+        arg_type = "str"
+    )]
+    pattern: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "regex_match",
+    description = "Tests whether a regular expression matches anywhere in a string.",
+    parameters(RegexMatchArgs),
+    parsed(RegexMatchFilter)
+)]
+pub(crate) struct RegexMatch;
+
+impl ParseFilter for RegexMatch {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = RegexMatchArgs::from_args(arguments)?;
+
+        Ok(Box::new(RegexMatchFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "regex_match"]
+struct RegexMatchFilter {
+    #[parameters]
+    args: RegexMatchArgs,
+}
+
+impl std::fmt::Debug for RegexMatchFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegexMatchFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for RegexMatchFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let regex = cached_regex(&args.pattern)?;
+
+        Ok(Value::scalar(regex.is_match(input.to_kstr().as_str())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ email | regex_match: '^[^@]+@example\\.com$' | output }}"}), json!({"email": "jane@example.com"}), json!({"this":true}))]
+    #[case(json!({"this":"{{ email | regex_match: '^[^@]+@example\\.com$' | output }}"}), json!({"email": "jane@example.org"}), json!({"this":false}))]
+    #[case(json!({"this":"{{ name | regex_match: '(?i)JANE' | output }}"}), json!({"name": "jane doe"}), json!({"this":true}))]
+    #[case(json!({"this":"{% assign client = code | regex_match: '^4' %}{% if client %}client{% endif %}"}), json!({"code": 404}), json!({"this":"client"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let tmpl = LiquidJson::new(json!("{{ 'abc' | regex_match: '(' }}"));
+        assert!(tmpl.render(&json!({})).is_err());
+    }
+}
@@ -0,0 +1,100 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::cached_regex;
+
+#[derive(Debug, FilterParameters)]
+struct RegexReplaceArgs {
+    #[parameter(description = "The regular expression to replace.", arg_type = "str")]
+    pattern: Expression,
+    #[parameter(
+        description = "The replacement, which can refer to capture groups as `$1` or `${name}`.",
+        arg_type = "str"
+    )]
+    replacement: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "regex_replace",
+    description = "Replaces every match of a regular expression in a string.",
+    parameters(RegexReplaceArgs),
+    parsed(RegexReplaceFilter)
+)]
+pub(crate) struct RegexReplace;
+
+impl ParseFilter for RegexReplace {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = RegexReplaceArgs::from_args(arguments)?;
+
+        Ok(Box::new(RegexReplaceFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "regex_replace"]
+struct RegexReplaceFilter {
+    #[parameters]
+    args: RegexReplaceArgs,
+}
+
+impl std::fmt::Debug for RegexReplaceFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegexReplaceFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for RegexReplaceFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let regex = cached_regex(&args.pattern)?;
+        let input = input.to_kstr();
+        let output = regex.replace_all(input.as_str(), args.replacement.as_str());
+
+        Ok(Value::scalar(output.into_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ phone | regex_replace: '\\D', '' }}"}), json!({"phone": "+1 (555) 010-9999"}), json!({"this":"15550109999"}))]
+    #[case(json!({"this":"{{ date | regex_replace: '(\\d+)/(\\d+)/(\\d+)', '$3-$1-$2' }}"}), json!({"date": "12/25/2024"}), json!({"this":"2024-12-25"}))]
+    #[case(json!({"this":"{{ name | regex_replace: '(?P<first>\\w+) (?P<last>\\w+)', '${last}, ${first}' }}"}), json!({"name": "Jane Doe"}), json!({"this":"Doe, Jane"}))]
+    #[case(json!({"this":"{{ name | regex_replace: 'x', 'y' }}"}), json!({"name": "Jane Doe"}), json!({"this":"Jane Doe"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let tmpl = LiquidJson::new(json!("{{ 'abc' | regex_replace: '[', '' }}"));
+        assert!(tmpl.render(&json!({})).is_err());
+    }
+}
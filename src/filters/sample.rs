@@ -0,0 +1,119 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+use rand::seq::SliceRandom;
+
+use super::{invalid_argument, invalid_input};
+use crate::environment::RandomRegister;
+
+#[derive(Debug, FilterParameters)]
+struct SampleArgs {
+    #[parameter(
+        description = "The number of elements to pick. Without it a single element is returned instead of an array.",
+        arg_type = "integer"
+    )]
+    count: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "sample",
+    description = "Picks random elements from an array.",
+    parameters(SampleArgs),
+    parsed(SampleFilter)
+)]
+pub(crate) struct Sample;
+
+impl ParseFilter for Sample {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = SampleArgs::from_args(arguments)?;
+
+        Ok(Box::new(SampleFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "sample"]
+struct SampleFilter {
+    #[parameters]
+    args: SampleArgs,
+}
+
+impl std::fmt::Debug for SampleFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SampleFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for SampleFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let input: Vec<_> = input
+            .as_array()
+            .ok_or_else(|| invalid_input("Array expected"))?
+            .values()
+            .collect();
+        let mut random = runtime.registers().get_mut::<RandomRegister>();
+
+        match args.count {
+            None => Ok(input
+                .choose(random.rng())
+                .map_or(Value::Nil, |v| v.to_value())),
+            Some(count) => {
+                let count = usize::try_from(count)
+                    .map_err(|_| invalid_argument("count", "Negative count"))?;
+                let output: Vec<_> = input
+                    .choose_multiple(random.rng(), count)
+                    .map(|v| v.to_value())
+                    .collect();
+                Ok(Value::array(output))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Environment, LiquidJson};
+    use serde_json::json;
+
+    use anyhow::Result;
+
+    #[test]
+    fn sample() -> Result<()> {
+        let data = json!({"items": [1, 2, 3, 4, 5]});
+        let tmpl = LiquidJson::new(
+            json!({"one": "{{ items | sample | output }}", "some": "{{ items | sample: 3 | output }}", "all": "{{ items | sample: 10 | output }}"}),
+        );
+        let env = Environment::new().with_seed(3);
+        let actual = tmpl.render_with(&data, &env)?;
+        assert_eq!(actual, tmpl.render_with(&data, &env)?);
+
+        let items = data["items"].as_array().unwrap();
+        assert!(items.contains(&actual["one"]));
+        let some = actual["some"].as_array().unwrap();
+        assert_eq!(some.len(), 3);
+        assert!(some.iter().all(|v| items.contains(v)));
+        assert_eq!(actual["all"].as_array().unwrap().len(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn empty() -> Result<()> {
+        let tmpl = LiquidJson::new(json!("{{ items | sample | output }}"));
+        assert_eq!(tmpl.render(&json!({"items": []}))?, json!(null));
+        Ok(())
+    }
+}
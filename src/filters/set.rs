@@ -0,0 +1,128 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Object, Value, ValueView};
+
+use super::invalid_input;
+
+#[derive(Debug, FilterParameters)]
+struct SetArgs {
+    #[parameter(
+        description = "The key to set. Dots separate the keys of nested objects.",
+        arg_type = "str"
+    )]
+    path: Expression,
+    #[parameter(description = "The value to set.", arg_type = "any")]
+    value: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "set",
+    description = "Sets a key of an object, creating intermediate objects as needed.",
+    parameters(SetArgs),
+    parsed(SetFilter)
+)]
+pub(crate) struct Set;
+
+impl ParseFilter for Set {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = SetArgs::from_args(arguments)?;
+
+        Ok(Box::new(SetFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "set"]
+struct SetFilter {
+    #[parameters]
+    args: SetArgs,
+}
+
+impl std::fmt::Debug for SetFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SetFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for SetFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let mut output = if input.is_nil() {
+            Object::new()
+        } else {
+            match input.to_value() {
+                Value::Object(object) => object,
+                _ => return Err(invalid_input("Object expected")),
+            }
+        };
+
+        let keys: Vec<_> = args.path.split('.').collect();
+        set(&mut output, &keys, args.value.to_value())?;
+        Ok(Value::Object(output))
+    }
+}
+
+fn set(object: &mut Object, keys: &[&str], value: Value) -> Result<()> {
+    match keys {
+        [] => Ok(()),
+        [key] => {
+            object.insert((*key).to_owned().into(), value);
+            Ok(())
+        }
+        [key, rest @ ..] => {
+            let child = object
+                .entry((*key).to_owned())
+                .or_insert_with(|| Value::Object(Object::new()));
+            if child.is_nil() {
+                *child = Value::Object(Object::new());
+            }
+            match child {
+                Value::Object(child) => set(child, rest, value),
+                _ => Err(invalid_input(format!("`{}` is not an object", key))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ myval | set: 'b', other | output }}"}), json!({"myval": {"a": 1}, "other": [2]}), json!({"this":{"a": 1, "b": [2]}}))]
+    #[case(json!({"this":"{{ myval | set: 'a.b.c', 2 | output }}"}), json!({"myval": {"a": {"d": 1}}}), json!({"this":{"a": {"b": {"c": 2}, "d": 1}}}))]
+    #[case(json!({"this":"{{ missing | set: 'a', 'x' | output }}"}), json!({}), json!({"this":{"a": "x"}}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn set_through_scalar() {
+        let tmpl = LiquidJson::new(json!("{{ myval | set: 'a.b', 1 }}"));
+        assert!(tmpl.render(&json!({"myval": {"a": 1}})).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::digest_value;
+
+#[derive(Debug, FilterParameters)]
+struct Sha1Args {
+    #[parameter(
+        description = "The encoding of the digest: `hex` (default) or `base64`.",
+        arg_type = "str",
+        mode = "keyword"
+    )]
+    encoding: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "sha1",
+    description = "Computes the SHA-1 digest of a string or an array of bytes.",
+    parameters(Sha1Args),
+    parsed(Sha1Filter)
+)]
+pub(crate) struct Sha1;
+
+impl ParseFilter for Sha1 {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = Sha1Args::from_args(arguments)?;
+
+        Ok(Box::new(Sha1Filter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "sha1"]
+struct Sha1Filter {
+    #[parameters]
+    args: Sha1Args,
+}
+
+impl std::fmt::Debug for Sha1Filter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sha1Filter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for Sha1Filter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        digest_value::<sha1::Sha1>(input, args.encoding.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"hash":"{{ 'hello' | sha1 }}"}), json!({}), json!({"hash": "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d"}))]
+    #[case(json!({"hash":"{{ 'hello' | sha1: encoding: 'base64' }}"}), json!({}), json!({"hash": "qvTGHdzF6KLavt4PO0gs2a6pQ00="}))]
+    #[case(json!({"hash":"{{ 'AAH/' | base64_decode: format: 'bytes' | sha1 }}"}), json!({}), json!({"hash": "c63e8274458bc7501e7c981f6394ced6d4490fda"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
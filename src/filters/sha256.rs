@@ -0,0 +1,95 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::digest_value;
+
+#[derive(Debug, FilterParameters)]
+struct Sha256Args {
+    #[parameter(
+        description = "The encoding of the digest: `hex` (default) or `base64`.",
+        arg_type = "str",
+        mode = "keyword"
+    )]
+    encoding: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "sha256",
+    description = "Computes the SHA-256 digest of a string or an array of bytes.",
+    parameters(Sha256Args),
+    parsed(Sha256Filter)
+)]
+pub(crate) struct Sha256;
+
+impl ParseFilter for Sha256 {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = Sha256Args::from_args(arguments)?;
+
+        Ok(Box::new(Sha256Filter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "sha256"]
+struct Sha256Filter {
+    #[parameters]
+    args: Sha256Args,
+}
+
+impl std::fmt::Debug for Sha256Filter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sha256Filter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for Sha256Filter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        digest_value::<sha2::Sha256>(input, args.encoding.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"hash":"{{ 'hello' | sha256 }}"}), json!({}), json!({"hash": "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"}))]
+    #[case(json!({"hash":"{{ 'hello' | sha256: encoding: 'base64' }}"}), json!({}), json!({"hash": "LPJNul+wow4m6DsqxbninhsWHlwfp0JecwQzYpOLmCQ="}))]
+    #[case(json!({"hash":"{{ body | sha256: encoding: 'hex' }}"}), json!({"body": ""}), json!({"hash": "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"}))]
+    #[case(json!({"hash":"{{ 'AAH/' | base64_decode: format: 'bytes' | sha256 }}"}), json!({}), json!({"hash": "26a66b061e8f48f39927c312f25293959729eee95978e2892d49d3512a5cc092"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_encoding() {
+        let tmpl = LiquidJson::new(json!("{{ 'hello' | sha256: encoding: 'base32' }}"));
+        assert!(tmpl.render(&json!({})).is_err());
+    }
+}
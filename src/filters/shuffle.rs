@@ -0,0 +1,68 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+use rand::seq::SliceRandom;
+
+use super::invalid_input;
+use crate::environment::RandomRegister;
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "shuffle",
+    description = "Randomly reorders the elements of an array.",
+    parsed(ShuffleFilter)
+)]
+pub(crate) struct Shuffle;
+
+#[derive(Default, Display_filter)]
+#[name = "shuffle"]
+struct ShuffleFilter;
+
+impl std::fmt::Debug for ShuffleFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShuffleFilter").finish()
+    }
+}
+
+impl Filter for ShuffleFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let mut output: Vec<_> = input
+            .as_array()
+            .ok_or_else(|| invalid_input("Array expected"))?
+            .values()
+            .map(|v| v.to_value())
+            .collect();
+
+        output.shuffle(runtime.registers().get_mut::<RandomRegister>().rng());
+        Ok(Value::array(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Environment, LiquidJson};
+    use serde_json::json;
+
+    use anyhow::Result;
+
+    #[test]
+    fn seeded() -> Result<()> {
+        let tmpl = LiquidJson::new(json!("{{ items | shuffle | output }}"));
+        let data = json!({"items": (0..20).collect::<Vec<_>>()});
+        let env = Environment::new().with_seed(1);
+        let first = tmpl.render_with(&data, &env)?;
+        assert_eq!(first, tmpl.render_with(&data, &env)?);
+        assert_ne!(first, data["items"]);
+
+        let mut sorted: Vec<_> = first
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_i64().unwrap())
+            .collect();
+        sorted.sort_unstable();
+        assert_eq!(json!(sorted), data["items"]);
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "slugify",
+    description = "Converts a string to a lowercase, hyphen-separated slug suitable for URLs.",
+    parsed(SlugifyFilter)
+)]
+pub(crate) struct Slugify;
+
+#[derive(Default, Display_filter)]
+#[name = "slugify"]
+struct SlugifyFilter;
+
+impl std::fmt::Debug for SlugifyFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SlugifyFilter").finish()
+    }
+}
+
+impl Filter for SlugifyFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        let input = input.to_kstr();
+        // Unlike the case filters, only punctuation and whitespace separate words, so `iPhone`
+        // stays one word. Apostrophes are dropped so `don't` doesn't become `don-t`.
+        let slug = input
+            .as_str()
+            .split(|c: char| !c.is_alphanumeric() && c != '\'' && c != '\u{2019}')
+            .map(|word| word.replace(['\'', '\u{2019}'], "").to_lowercase())
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+
+        Ok(Value::scalar(slug))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case("Hello, World!", "hello-world")]
+    #[case("  Release 1.2 -- Notes  ", "release-1-2-notes")]
+    #[case("Don't Stop", "dont-stop")]
+    #[case("iPhone_case", "iphone-case")]
+    #[case("Crème Brûlée", "crème-brûlée")]
+    #[case("!!!", "")]
+    fn filters(#[case] input: &str, #[case] expected: &str) -> Result<()> {
+        let tmpl = LiquidJson::new(json!("{{ input | slugify }}"));
+        let actual = tmpl.render(&json!({ "input": input }))?;
+        assert_eq!(actual, Value::String(expected.to_owned()));
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+use super::Case;
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "snake_case",
+    description = "Converts a string to snake_case.",
+    parsed(SnakeCaseFilter)
+)]
+pub(crate) struct SnakeCase;
+
+#[derive(Default, Display_filter)]
+#[name = "snake_case"]
+struct SnakeCaseFilter;
+
+impl std::fmt::Debug for SnakeCaseFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SnakeCaseFilter").finish()
+    }
+}
+
+impl Filter for SnakeCaseFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        Ok(Value::scalar(Case::Snake.convert(input.to_kstr().as_str())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case("user_id", "user_id")]
+    #[case("userId", "user_id")]
+    #[case("UserID", "user_id")]
+    #[case("HTTPServerError", "http_server_error")]
+    #[case("first-name", "first_name")]
+    #[case("SCREAMING_SNAKE", "screaming_snake")]
+    #[case("version2Name", "version2_name")]
+    #[case(" leading  spaces ", "leading_spaces")]
+    #[case("", "")]
+    fn filters(#[case] input: &str, #[case] expected: &str) -> Result<()> {
+        let tmpl = LiquidJson::new(json!("{{ input | snake_case }}"));
+        let actual = tmpl.render(&json!({ "input": input }))?;
+        assert_eq!(actual, Value::String(expected.to_owned()));
+        Ok(())
+    }
+}
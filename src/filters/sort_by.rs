@@ -0,0 +1,165 @@
+use std::cmp::Ordering;
+
+use liquid_core::model::KString;
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_input, property};
+
+#[derive(Debug, FilterParameters)]
+struct SortByArgs {
+    #[parameter(
+        description = "The property to sort by, e.g. `price` or `owner.name`.",
+        arg_type = "str"
+    )]
+    property: Expression,
+    #[parameter(
+        description = "Sort in descending order.",
+        arg_type = "bool",
+        mode = "keyword"
+    )]
+    reverse: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "sort_by",
+    description = "Sorts an array by a property of each element, keeping the order of equal elements.",
+    parameters(SortByArgs),
+    parsed(SortByFilter)
+)]
+pub(crate) struct SortBy;
+
+impl ParseFilter for SortBy {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = SortByArgs::from_args(arguments)?;
+
+        Ok(Box::new(SortByFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "sort_by"]
+struct SortByFilter {
+    #[parameters]
+    args: SortByArgs,
+}
+
+impl std::fmt::Debug for SortByFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SortByFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for SortByFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let input = input
+            .as_array()
+            .ok_or_else(|| invalid_input("Array expected"))?;
+        let reverse = args.reverse.unwrap_or(false);
+        let mut elements: Vec<_> = input
+            .values()
+            .map(|element| (property(element, &args.property), element))
+            .collect();
+        // `sort_by` is stable, so equal elements keep their order in both directions.
+        elements.sort_by(|(a, _), (b, _)| match (sort_key(*a), sort_key(*b)) {
+            // Elements without the property always go last.
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (Some(a), Some(b)) if reverse => b.cmp(&a),
+            (Some(a), Some(b)) => a.cmp(&b),
+        });
+
+        Ok(Value::array(
+            elements.into_iter().map(|(_, element)| element.to_value()),
+        ))
+    }
+}
+
+/// Orders numbers before strings before any other values, comparing numbers by value and
+/// everything else by its text.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum SortKey {
+    Number(Float),
+    Text(KString),
+    Other(KString),
+}
+
+#[derive(PartialEq)]
+struct Float(f64);
+
+impl Eq for Float {}
+
+impl PartialOrd for Float {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Float {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+fn sort_key(value: Option<&dyn ValueView>) -> Option<SortKey> {
+    let value = value.filter(|v| !v.is_nil())?;
+    let key = match value.as_scalar() {
+        Some(scalar) if scalar.type_name().ends_with("number") => {
+            SortKey::Number(Float(scalar.to_float()?))
+        }
+        Some(scalar) if scalar.type_name() == "string" => {
+            SortKey::Text(scalar.to_kstr().into_owned())
+        }
+        _ => SortKey::Other(value.to_kstr().into_owned()),
+    };
+    Some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ items | sort_by: 'price' | map: 'id' | output }}"}), json!({"items": [{"id": "a", "price": 10}, {"id": "b", "price": 2.5}, {"id": "c", "price": 7}]}), json!({"this":["b","c","a"]}))]
+    #[case(json!({"this":"{{ items | sort_by: 'price', reverse: true | map: 'id' | output }}"}), json!({"items": [{"id": "a", "price": 10}, {"id": "b", "price": 2.5}, {"id": "c", "price": 7}]}), json!({"this":["a","c","b"]}))]
+    #[case(json!({"this":"{{ items | sort_by: 'rank' | map: 'id' | output }}"}), json!({"items": [{"id": "a", "rank": 1}, {"id": "b"}, {"id": "c", "rank": 1}, {"id": "d", "rank": 0}]}), json!({"this":["d","a","c","b"]}))]
+    #[case(json!({"this":"{{ items | sort_by: 'rank', reverse: true | map: 'id' | output }}"}), json!({"items": [{"id": "a", "rank": 1}, {"id": "b"}, {"id": "c", "rank": 1}, {"id": "d", "rank": 0}]}), json!({"this":["a","c","d","b"]}))]
+    #[case(json!({"this":"{{ users | sort_by: 'name.last' | map: 'id' | output }}"}), json!({"users": [{"id": 1, "name": {"last": "Smith"}}, {"id": 2, "name": {"last": "Doe"}}]}), json!({"this":[2,1]}))]
+    #[case(json!({"this":"{{ items | sort_by: 'v' | map: 'v' | output }}"}), json!({"items": [{"v": "b"}, {"v": 10}, {"v": "a"}, {"v": 9}]}), json!({"this":[9,10,"a","b"]}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let tmpl = LiquidJson::new(json!("{{ items | sort_by: 'price' }}"));
+        assert!(tmpl.render(&json!({"items": {"price": 1}})).is_err());
+    }
+}
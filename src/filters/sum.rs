@@ -0,0 +1,120 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_input, present_values, Number};
+
+#[derive(Debug, FilterParameters)]
+struct SumArgs {
+    #[parameter(
+        description = "The property of each element to add up, e.g. `price` or `totals.net`.",
+        arg_type = "str"
+    )]
+    property: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "sum",
+    description = "Adds up the numbers in an array, or a property of each element.",
+    parameters(SumArgs),
+    parsed(SumFilter)
+)]
+pub(crate) struct Sum;
+
+impl ParseFilter for Sum {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = SumArgs::from_args(arguments)?;
+
+        Ok(Box::new(SumFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "sum"]
+struct SumFilter {
+    #[parameters]
+    args: SumArgs,
+}
+
+impl std::fmt::Debug for SumFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SumFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for SumFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let values = present_values(input, args.property.as_deref())?;
+        // Stay a whole number until a fractional number shows up.
+        let mut total = Number::Integer(0);
+        for value in values {
+            total = match (total, Number::from_value(value)?) {
+                (Number::Integer(a), Number::Integer(b)) => Number::Integer(
+                    a.checked_add(b)
+                        .ok_or_else(|| invalid_input("Sum is too large"))?,
+                ),
+                (a, b) => Number::Float(a.to_f64() + b.to_f64()),
+            };
+        }
+        if let Number::Float(total) = total {
+            if !total.is_finite() {
+                return Err(invalid_input("Sum is too large"));
+            }
+        }
+
+        Ok(total.into_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ nums | sum | output }}"}), json!({"nums": [1, 2, 3]}), json!({"this":6}))]
+    #[case(json!({"this":"{{ nums | sum | output }}"}), json!({"nums": [1, 2.5]}), json!({"this":3.5}))]
+    #[case(json!({"this":"{{ nums | sum | output }}"}), json!({"nums": []}), json!({"this":0}))]
+    #[case(json!({"this":"{{ nums | sum | output }}"}), json!({"nums": ["4", null, 1]}), json!({"this":5}))]
+    #[case(json!({"this":"{{ items | sum: 'price' | output }}"}), json!({"items": [{"price": 10}, {"price": 5}, {"name": "free"}]}), json!({"this":15}))]
+    #[case(json!({"this":"{{ items | sum: 'totals.net' | output }}"}), json!({"items": [{"totals": {"net": 1.25}}, {"totals": {"net": 2}}]}), json!({"this":3.25}))]
+    #[case(json!({"this":"{{ items | sum: 'price' }}"}), json!({"items": [{"price": 10}, {"price": 5}]}), json!({"this":"15"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!("{{ nums | sum }}"), json!({"nums": ["one"]}))]
+    #[case(json!("{{ nums | sum }}"), json!({"nums": 1}))]
+    #[case(json!("{{ nums | sum }}"), json!({"nums": [9_223_372_036_854_775_807_i64, 1]}))]
+    #[case(json!("{{ nums | sum | output }}"), json!({"nums": [1e308, 1e308]}))]
+    #[case(json!("{{ nums | sum: 'n' }}"), json!({"nums": [{"n": 1.5e308}, {"n": 1}, {"n": 1.5e308}]}))]
+    fn invalid(#[case] template: Value, #[case] data: Value) {
+        let tmpl = LiquidJson::new(template);
+        assert!(tmpl.render(&data).is_err());
+    }
+}
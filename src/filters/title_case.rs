@@ -0,0 +1,56 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+use super::Case;
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "title_case",
+    description = "Converts a string to Title Case.",
+    parsed(TitleCaseFilter)
+)]
+pub(crate) struct TitleCase;
+
+#[derive(Default, Display_filter)]
+#[name = "title_case"]
+struct TitleCaseFilter;
+
+impl std::fmt::Debug for TitleCaseFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TitleCaseFilter").finish()
+    }
+}
+
+impl Filter for TitleCaseFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        Ok(Value::scalar(Case::Title.convert(input.to_kstr().as_str())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case("user_id", "User Id")]
+    #[case("userId", "User Id")]
+    #[case("UserID", "User Id")]
+    #[case("HTTPServerError", "Http Server Error")]
+    #[case("first-name", "First Name")]
+    #[case("SCREAMING_SNAKE", "Screaming Snake")]
+    #[case("version2Name", "Version2 Name")]
+    #[case(" leading  spaces ", "Leading Spaces")]
+    #[case("", "")]
+    fn filters(#[case] input: &str, #[case] expected: &str) -> Result<()> {
+        let tmpl = LiquidJson::new(json!("{{ input | title_case }}"));
+        let actual = tmpl.render(&json!({ "input": input }))?;
+        assert_eq!(actual, Value::String(expected.to_owned()));
+        Ok(())
+    }
+}
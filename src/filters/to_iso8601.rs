@@ -0,0 +1,59 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+use time::format_description::well_known::Rfc3339;
+
+use super::input_date;
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "to_iso8601",
+    description = "Formats a date as an ISO 8601 (RFC 3339) string.",
+    parsed(ToIso8601Filter)
+)]
+pub(crate) struct ToIso8601;
+
+#[derive(Default, Display_filter)]
+#[name = "to_iso8601"]
+struct ToIso8601Filter;
+
+impl std::fmt::Debug for ToIso8601Filter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToIso8601Filter").finish()
+    }
+}
+
+impl Filter for ToIso8601Filter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let date = input_date(input, runtime)?;
+        let formatted = date
+            .format(&Rfc3339)
+            .map_err(|e| liquid_core::Error::with_msg(e.to_string()))?;
+
+        Ok(Value::Scalar(formatted.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"d":"{{ created | to_iso8601 }}"}), json!({"created": 1700000000}), json!({"d": "2023-11-14T22:13:20Z"}))]
+    #[case(json!({"d":"{{ created | to_iso8601 }}"}), json!({"created": "14 Nov 2023 22:13:20 +0100"}), json!({"d": "2023-11-14T22:13:20+01:00"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
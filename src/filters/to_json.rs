@@ -0,0 +1,108 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+
+use crate::to_json_value;
+
+#[derive(Debug, FilterParameters)]
+struct ToJsonArgs {
+    #[parameter(
+        description = "Indent the JSON output instead of producing compact JSON.",
+        arg_type = "bool",
+        mode = "keyword"
+    )]
+    pretty: Option<Expression>,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "to_json",
+    description = "Serializes a value into a JSON string with object keys in sorted order.",
+    parameters(ToJsonArgs),
+    parsed(ToJsonFilter)
+)]
+pub(crate) struct ToJson;
+
+impl ParseFilter for ToJson {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = ToJsonArgs::from_args(arguments)?;
+
+        Ok(Box::new(ToJsonFilter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "to_json"]
+struct ToJsonFilter {
+    #[parameters]
+    args: ToJsonArgs,
+}
+
+impl std::fmt::Debug for ToJsonFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToJsonFilter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for ToJsonFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        // serde_json maps are ordered by key, which keeps the output deterministic even though
+        // Liquid objects are unordered.
+        let value = to_json_value(input.to_value())?;
+        let json = if args.pretty.unwrap_or(false) {
+            serde_json::to_string_pretty(&value)
+        } else {
+            serde_json::to_string(&value)
+        }
+        .map_err(|e| liquid_core::Error::with_msg(e.to_string()))?;
+
+        Ok(Value::Scalar(json.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ myval | to_json }}"}), json!({"myval": {"b": [1, 2.5], "a": "x\"y", "c": null}}), json!({"this":"{\"a\":\"x\\\"y\",\"b\":[1,2.5],\"c\":null}"}))]
+    #[case(json!({"this":"{{ myval | to_json: pretty: true }}"}), json!({"myval": {"b": true, "a": 1}}), json!({"this":"{\n  \"a\": 1,\n  \"b\": true\n}"}))]
+    #[case(json!({"this":"{{ myval | to_json }}"}), json!({"myval": "text"}), json!({"this":"\"text\""}))]
+    #[case(json!({"this":"{{ empty | to_json }}"}), json!({}), json!({"this":"\"\""}))]
+    #[case(json!({"this":"{{ blank | to_json }}"}), json!({}), json!({"this":"\"\""}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!("{{ n | times: 10 | to_json }}"))]
+    #[case(json!("{{ n | times: 10 | output }}"))]
+    fn non_finite(#[case] template: Value) {
+        let tmpl = LiquidJson::new(template);
+        assert!(tmpl.render(&json!({"n": 1e308})).is_err());
+    }
+}
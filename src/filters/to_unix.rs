@@ -0,0 +1,55 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+use super::input_date;
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "to_unix",
+    description = "Converts a date to a unix timestamp in seconds.",
+    parsed(ToUnixFilter)
+)]
+pub(crate) struct ToUnix;
+
+#[derive(Default, Display_filter)]
+#[name = "to_unix"]
+struct ToUnixFilter;
+
+impl std::fmt::Debug for ToUnixFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToUnixFilter").finish()
+    }
+}
+
+impl Filter for ToUnixFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let date = input_date(input, runtime)?;
+
+        Ok(Value::scalar(date.unix_timestamp()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"d":"{{ created | to_unix | output }}"}), json!({"created": "2023-11-14T23:13:20+01:00"}), json!({"d": 1700000000}))]
+    #[case(json!({"d":"{{ created | to_unix | output }}"}), json!({"created": "1969-12-31"}), json!({"d": -86400}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+use rand::Rng;
+
+use crate::environment::RandomRegister;
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "uuid_v4",
+    description = "Generates a random UUID, ignoring the input.",
+    parsed(UuidV4Filter)
+)]
+pub(crate) struct UuidV4;
+
+#[derive(Default, Display_filter)]
+#[name = "uuid_v4"]
+struct UuidV4Filter;
+
+impl std::fmt::Debug for UuidV4Filter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UuidV4Filter").finish()
+    }
+}
+
+impl Filter for UuidV4Filter {
+    fn evaluate(&self, _input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let bytes = runtime.registers().get_mut::<RandomRegister>().rng().gen();
+        let uuid = uuid::Builder::from_random_bytes(bytes).into_uuid();
+
+        Ok(Value::Scalar(uuid.to_string().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Environment, LiquidJson};
+    use serde_json::json;
+
+    use anyhow::Result;
+
+    #[test]
+    fn seeded() -> Result<()> {
+        let tmpl = LiquidJson::new(json!({"a": "{{ '' | uuid_v4 }}", "b": "{{ '' | uuid_v4 }}"}));
+        let env = Environment::new().with_seed(42);
+        let first = tmpl.render_with(&json!({}), &env)?;
+        let second = tmpl.render_with(&json!({}), &env)?;
+        assert_eq!(first, second);
+        assert_ne!(first["a"], first["b"]);
+        let uuid = uuid::Uuid::parse_str(first["a"].as_str().unwrap())?;
+        assert_eq!(uuid.get_version_num(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn unseeded() -> Result<()> {
+        let tmpl = LiquidJson::new(json!("{{ '' | uuid_v4 }}"));
+        assert_ne!(tmpl.render(&json!({}))?, tmpl.render(&json!({}))?);
+        Ok(())
+    }
+}
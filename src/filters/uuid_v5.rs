@@ -0,0 +1,107 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{
+    Display_filter, Filter, FilterParameters, FilterReflection, FromFilterParameters, ParseFilter,
+};
+use liquid_core::{Value, ValueView};
+use uuid::Uuid;
+
+use super::invalid_argument;
+
+#[derive(Debug, FilterParameters)]
+struct UuidV5Args {
+    #[parameter(
+        description = "The namespace UUID, or one of `dns`, `url`, `oid` and `x500`.",
+        arg_type = "str"
+    )]
+    namespace: Expression,
+}
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "uuid_v5",
+    description = "Generates a name-based UUID from the input and a namespace.",
+    parameters(UuidV5Args),
+    parsed(UuidV5Filter)
+)]
+pub(crate) struct UuidV5;
+
+impl ParseFilter for UuidV5 {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let args = UuidV5Args::from_args(arguments)?;
+
+        Ok(Box::new(UuidV5Filter { args }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(FromFilterParameters, Display_filter)]
+#[name = "uuid_v5"]
+struct UuidV5Filter {
+    #[parameters]
+    args: UuidV5Args,
+}
+
+impl std::fmt::Debug for UuidV5Filter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UuidV5Filter")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Filter for UuidV5Filter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let args = self.args.evaluate(runtime)?;
+
+        let namespace = match args.namespace.as_str() {
+            "dns" => Uuid::NAMESPACE_DNS,
+            "url" => Uuid::NAMESPACE_URL,
+            "oid" => Uuid::NAMESPACE_OID,
+            "x500" => Uuid::NAMESPACE_X500,
+            namespace => Uuid::parse_str(namespace).map_err(|_| {
+                invalid_argument(
+                    "namespace",
+                    "Expected a UUID, `dns`, `url`, `oid` or `x500`",
+                )
+            })?,
+        };
+        let uuid = Uuid::new_v5(&namespace, input.to_kstr().as_bytes());
+
+        Ok(Value::Scalar(uuid.to_string().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"id":"{{ 'example.com' | uuid_v5: 'dns' }}"}), json!({}), json!({"id": "cfbff0d1-9375-5685-968c-48ce8b15ae17"}))]
+    #[case(json!({"id":"{{ 'example.com' | uuid_v5: ns }}"}), json!({"ns": "6ba7b810-9dad-11d1-80b4-00c04fd430c8"}), json!({"id": "cfbff0d1-9375-5685-968c-48ce8b15ae17"}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_namespace() {
+        let tmpl = LiquidJson::new(json!("{{ 'example.com' | uuid_v5: 'nope' }}"));
+        assert!(tmpl.render(&json!({})).is_err());
+    }
+}
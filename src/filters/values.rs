@@ -0,0 +1,61 @@
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_input, sorted_entries};
+
+#[derive(Clone, ParseFilter, FilterReflection)]
+#[filter(
+    name = "values",
+    description = "Returns the values of an object, ordered by their keys.",
+    parsed(ValuesFilter)
+)]
+pub(crate) struct Values;
+
+#[derive(Default, Display_filter)]
+#[name = "values"]
+struct ValuesFilter;
+
+impl std::fmt::Debug for ValuesFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValuesFilter").finish()
+    }
+}
+
+impl Filter for ValuesFilter {
+    fn evaluate(&self, input: &dyn ValueView, _runtime: &dyn Runtime) -> Result<Value> {
+        let input = input
+            .as_object()
+            .ok_or_else(|| invalid_input("Object expected"))?;
+
+        let output: Vec<_> = sorted_entries(input)
+            .into_iter()
+            .map(|(_, v)| v.to_value())
+            .collect();
+        Ok(Value::array(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ myval | values | output }}"}), json!({"myval": {"b": 1, "a": {"c": 2}}}), json!({"this":[{"c": 2},1]}))]
+    #[case(json!({"this":"{{ myval | values | output }}"}), json!({"myval": {}}), json!({"this":[]}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use liquid_core::parser::FilterArguments;
+use liquid_core::Expression;
+use liquid_core::Result;
+use liquid_core::Runtime;
+use liquid_core::{Display_filter, Filter, FilterReflection, ParseFilter};
+use liquid_core::{Value, ValueView};
+
+use super::{invalid_input, variadic_args};
+
+#[derive(Clone, FilterReflection)]
+#[filter(
+    name = "zip",
+    description = "Combines an array with other arrays into an array of tuples, stopping at the end of the shortest array.",
+    parsed(ZipFilter)
+)]
+pub(crate) struct Zip;
+
+impl ParseFilter for Zip {
+    fn parse(&self, arguments: FilterArguments) -> Result<Box<dyn Filter>> {
+        let others = variadic_args(arguments)?;
+
+        Ok(Box::new(ZipFilter { others }))
+    }
+
+    fn reflection(&self) -> &dyn FilterReflection {
+        self
+    }
+}
+
+#[derive(Display_filter)]
+#[name = "zip"]
+struct ZipFilter {
+    others: Vec<Expression>,
+}
+
+impl std::fmt::Debug for ZipFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ZipFilter")
+            .field("others", &self.others)
+            .finish()
+    }
+}
+
+impl Filter for ZipFilter {
+    fn evaluate(&self, input: &dyn ValueView, runtime: &dyn Runtime) -> Result<Value> {
+        let others = self
+            .others
+            .iter()
+            .map(|other| other.evaluate(runtime))
+            .collect::<Result<Vec<_>>>()?;
+        let arrays = std::iter::once(input)
+            .chain(others.iter().map(|other| other.as_view()))
+            .map(|array| {
+                array
+                    .as_array()
+                    .ok_or_else(|| invalid_input("Array expected"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let len = arrays.iter().map(|array| array.size()).min().unwrap_or(0);
+
+        let output = (0..len).map(|i| {
+            Value::array(arrays.iter().map(|array| {
+                array
+                    .get(i)
+                    .map_or(Value::Nil, |element| element.to_value())
+            }))
+        });
+        Ok(Value::array(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LiquidJson;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"this":"{{ names | zip: ages | output }}"}), json!({"names": ["a", "b"], "ages": [1, 2]}), json!({"this":[["a",1],["b",2]]}))]
+    #[case(json!({"this":"{{ names | zip: ages, flags | output }}"}), json!({"names": ["a", "b", "c"], "ages": [1, 2], "flags": [true, false, true]}), json!({"this":[["a",1,true],["b",2,false]]}))]
+    #[case(json!({"this":"{{ names | zip | output }}"}), json!({"names": ["a"]}), json!({"this":[["a"]]}))]
+    #[case(json!({"this":"{{ names | zip: ages | from_entries | output }}"}), json!({"names": ["a", "b"], "ages": [1, 2]}), json!({"this":{"a":1,"b":2}}))]
+    fn filters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render(&data)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let tmpl = LiquidJson::new(json!("{{ names | zip: ages }}"));
+        assert!(tmpl.render(&json!({"names": ["a"], "ages": 1})).is_err());
+    }
+}
@@ -0,0 +1,432 @@
+use std::fmt;
+
+use liquid_core::model::{ArrayView, DisplayCow, KStringCow, ObjectView, ScalarCow, State};
+use liquid_core::{Value, ValueView};
+use ref_cast::RefCast;
+use serde_json::{Map, Number};
+
+use crate::Error;
+
+/// A [ValueView] over a borrowed JSON value, so templates can read render data without converting
+/// it to Liquid values first.
+#[derive(RefCast)]
+#[repr(transparent)]
+pub(crate) struct JsonView(serde_json::Value);
+
+/// An [ArrayView] over a borrowed JSON array.
+#[derive(RefCast)]
+#[repr(transparent)]
+pub(crate) struct JsonArray(Vec<serde_json::Value>);
+
+/// An [ObjectView] over a borrowed JSON object.
+#[derive(RefCast)]
+#[repr(transparent)]
+pub(crate) struct JsonObject(Map<String, serde_json::Value>);
+
+/// Get the object that render data must be, checking it only holds values Liquid supports.
+pub(crate) fn context(data: &serde_json::Value) -> Result<&Map<String, serde_json::Value>, Error> {
+    let serde_json::Value::Object(object) = data else {
+        return Err(Error::InvalidContext(data.clone()));
+    };
+    check_numbers(data)?;
+    Ok(object)
+}
+
+fn check_numbers(value: &serde_json::Value) -> Result<(), Error> {
+    match value {
+        serde_json::Value::Number(n) if n.is_u64() && !n.is_i64() => Err(Error::U64),
+        serde_json::Value::Array(a) => a.iter().try_for_each(check_numbers),
+        serde_json::Value::Object(o) => o.values().try_for_each(check_numbers),
+        _ => Ok(()),
+    }
+}
+
+/// The Liquid scalar a JSON number stands for.
+fn number(n: &Number) -> ScalarCow<'static> {
+    n.as_i64().map_or_else(
+        || ScalarCow::new(n.as_f64().unwrap_or_default()),
+        ScalarCow::new,
+    )
+}
+
+fn as_view(value: &serde_json::Value) -> &dyn ValueView {
+    JsonView::new(value)
+}
+
+impl JsonView {
+    pub(crate) fn new(value: &serde_json::Value) -> &Self {
+        Self::ref_cast(value)
+    }
+}
+
+impl JsonArray {
+    pub(crate) fn new(array: &Vec<serde_json::Value>) -> &Self {
+        Self::ref_cast(array)
+    }
+}
+
+impl JsonObject {
+    pub(crate) fn new(object: &Map<String, serde_json::Value>) -> &Self {
+        Self::ref_cast(object)
+    }
+}
+
+impl fmt::Debug for JsonView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for JsonArray {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for JsonObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ValueView for JsonView {
+    fn as_debug(&self) -> &dyn fmt::Debug {
+        self
+    }
+
+    fn render(&self) -> DisplayCow<'_> {
+        match &self.0 {
+            serde_json::Value::Null => DisplayCow::Borrowed(&""),
+            serde_json::Value::Bool(b) => DisplayCow::Borrowed(b),
+            serde_json::Value::Number(n) => {
+                DisplayCow::Owned(Box::new(number(n).render().to_string()))
+            }
+            serde_json::Value::String(s) => DisplayCow::Borrowed(s),
+            serde_json::Value::Array(a) => JsonArray::new(a).render(),
+            serde_json::Value::Object(o) => JsonObject::new(o).render(),
+        }
+    }
+
+    fn source(&self) -> DisplayCow<'_> {
+        match &self.0 {
+            serde_json::Value::Null => DisplayCow::Borrowed(&"nil"),
+            serde_json::Value::Bool(b) => DisplayCow::Borrowed(b),
+            serde_json::Value::Number(n) => {
+                DisplayCow::Owned(Box::new(number(n).source().to_string()))
+            }
+            serde_json::Value::String(s) => s.source(),
+            serde_json::Value::Array(a) => JsonArray::new(a).source(),
+            serde_json::Value::Object(o) => JsonObject::new(o).source(),
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match &self.0 {
+            serde_json::Value::Null => "nil",
+            serde_json::Value::Bool(b) => b.type_name(),
+            serde_json::Value::Number(n) => number(n).type_name(),
+            serde_json::Value::String(s) => s.type_name(),
+            serde_json::Value::Array(a) => JsonArray::new(a).type_name(),
+            serde_json::Value::Object(o) => JsonObject::new(o).type_name(),
+        }
+    }
+
+    fn query_state(&self, state: State) -> bool {
+        match &self.0 {
+            serde_json::Value::Null => Value::Nil.query_state(state),
+            serde_json::Value::Bool(b) => b.query_state(state),
+            serde_json::Value::Number(n) => number(n).query_state(state),
+            serde_json::Value::String(s) => s.query_state(state),
+            serde_json::Value::Array(a) => JsonArray::new(a).query_state(state),
+            serde_json::Value::Object(o) => JsonObject::new(o).query_state(state),
+        }
+    }
+
+    fn to_kstr(&self) -> KStringCow<'_> {
+        match &self.0 {
+            serde_json::Value::Null => KStringCow::from_static(""),
+            serde_json::Value::Bool(b) => b.to_kstr(),
+            serde_json::Value::Number(n) => number(n).to_kstr().into_owned().into(),
+            serde_json::Value::String(s) => s.to_kstr(),
+            serde_json::Value::Array(a) => JsonArray::new(a).to_kstr(),
+            serde_json::Value::Object(o) => JsonObject::new(o).to_kstr(),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        match &self.0 {
+            serde_json::Value::Null => Value::Nil,
+            serde_json::Value::Bool(b) => b.to_value(),
+            serde_json::Value::Number(n) => number(n).to_value(),
+            serde_json::Value::String(s) => s.to_value(),
+            serde_json::Value::Array(a) => JsonArray::new(a).to_value(),
+            serde_json::Value::Object(o) => JsonObject::new(o).to_value(),
+        }
+    }
+
+    fn as_scalar(&self) -> Option<ScalarCow<'_>> {
+        match &self.0 {
+            serde_json::Value::Bool(b) => Some(ScalarCow::new(*b)),
+            serde_json::Value::Number(n) => Some(number(n)),
+            serde_json::Value::String(s) => Some(ScalarCow::new(s.as_str())),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&dyn ArrayView> {
+        match &self.0 {
+            serde_json::Value::Array(a) => Some(JsonArray::new(a)),
+            _ => None,
+        }
+    }
+
+    fn as_object(&self) -> Option<&dyn ObjectView> {
+        match &self.0 {
+            serde_json::Value::Object(o) => Some(JsonObject::new(o)),
+            _ => None,
+        }
+    }
+
+    fn is_nil(&self) -> bool {
+        self.0.is_null()
+    }
+}
+
+impl ValueView for JsonArray {
+    fn as_debug(&self) -> &dyn fmt::Debug {
+        self
+    }
+
+    fn render(&self) -> DisplayCow<'_> {
+        DisplayCow::Owned(Box::new(ArrayRender(self)))
+    }
+
+    fn source(&self) -> DisplayCow<'_> {
+        DisplayCow::Owned(Box::new(ArraySource(self)))
+    }
+
+    fn type_name(&self) -> &'static str {
+        "array"
+    }
+
+    fn query_state(&self, state: State) -> bool {
+        match state {
+            State::Truthy => true,
+            State::DefaultValue | State::Empty | State::Blank => self.0.is_empty(),
+        }
+    }
+
+    fn to_kstr(&self) -> KStringCow<'_> {
+        KStringCow::from_string(ArrayRender(self).to_string())
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Array(self.0.iter().map(|v| JsonView::new(v).to_value()).collect())
+    }
+
+    fn as_array(&self) -> Option<&dyn ArrayView> {
+        Some(self)
+    }
+}
+
+impl ArrayView for JsonArray {
+    fn as_value(&self) -> &dyn ValueView {
+        self
+    }
+
+    fn size(&self) -> i64 {
+        self.0.len() as i64
+    }
+
+    fn values<'k>(&'k self) -> Box<dyn Iterator<Item = &'k dyn ValueView> + 'k> {
+        Box::new(self.0.iter().map(as_view))
+    }
+
+    fn contains_key(&self, index: i64) -> bool {
+        self.get(index).is_some()
+    }
+
+    fn get(&self, index: i64) -> Option<&dyn ValueView> {
+        // Negative indexes count from the end, like they do for Liquid arrays.
+        let index = if index < 0 {
+            index + self.size()
+        } else {
+            index
+        };
+        let value = usize::try_from(index).ok().and_then(|i| self.0.get(i))?;
+        Some(JsonView::new(value))
+    }
+}
+
+impl ValueView for JsonObject {
+    fn as_debug(&self) -> &dyn fmt::Debug {
+        self
+    }
+
+    fn render(&self) -> DisplayCow<'_> {
+        DisplayCow::Owned(Box::new(ObjectRender(self)))
+    }
+
+    fn source(&self) -> DisplayCow<'_> {
+        DisplayCow::Owned(Box::new(ObjectSource(self)))
+    }
+
+    fn type_name(&self) -> &'static str {
+        "object"
+    }
+
+    fn query_state(&self, state: State) -> bool {
+        match state {
+            State::Truthy => true,
+            State::DefaultValue | State::Empty | State::Blank => self.0.is_empty(),
+        }
+    }
+
+    fn to_kstr(&self) -> KStringCow<'_> {
+        KStringCow::from_string(ObjectRender(self).to_string())
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Object(
+            self.0
+                .iter()
+                .map(|(k, v)| (k.clone().into(), JsonView::new(v).to_value()))
+                .collect(),
+        )
+    }
+
+    fn as_object(&self) -> Option<&dyn ObjectView> {
+        Some(self)
+    }
+}
+
+impl ObjectView for JsonObject {
+    fn as_value(&self) -> &dyn ValueView {
+        self
+    }
+
+    fn size(&self) -> i64 {
+        self.0.len() as i64
+    }
+
+    fn keys<'k>(&'k self) -> Box<dyn Iterator<Item = KStringCow<'k>> + 'k> {
+        Box::new(self.0.keys().map(|k| k.as_str().into()))
+    }
+
+    fn values<'k>(&'k self) -> Box<dyn Iterator<Item = &'k dyn ValueView> + 'k> {
+        Box::new(self.0.values().map(as_view))
+    }
+
+    fn iter<'k>(&'k self) -> Box<dyn Iterator<Item = (KStringCow<'k>, &'k dyn ValueView)> + 'k> {
+        Box::new(self.0.iter().map(|(k, v)| (k.as_str().into(), as_view(v))))
+    }
+
+    fn contains_key(&self, index: &str) -> bool {
+        self.0.contains_key(index)
+    }
+
+    fn get<'s>(&'s self, index: &str) -> Option<&'s dyn ValueView> {
+        self.0.get(index).map(as_view)
+    }
+}
+
+struct ArrayRender<'s>(&'s JsonArray);
+
+impl fmt::Display for ArrayRender<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for value in ArrayView::values(self.0) {
+            write!(f, "{}", value.render())?;
+        }
+        Ok(())
+    }
+}
+
+struct ArraySource<'s>(&'s JsonArray);
+
+impl fmt::Display for ArraySource<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for value in ArrayView::values(self.0) {
+            write!(f, "{}, ", value.render())?;
+        }
+        write!(f, "]")
+    }
+}
+
+struct ObjectRender<'s>(&'s JsonObject);
+
+impl fmt::Display for ObjectRender<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, value) in ObjectView::iter(self.0) {
+            write!(f, "{}{}", key, value.render())?;
+        }
+        Ok(())
+    }
+}
+
+struct ObjectSource<'s>(&'s JsonObject);
+
+impl fmt::Display for ObjectSource<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (key, value) in ObjectView::iter(self.0) {
+            write!(f, r#""{}": {}, "#, key, value.render())?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use liquid_core::runtime::RuntimeBuilder;
+    use liquid_core::{Renderable, Template};
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{to_liquid_value, OPTIONS};
+
+    fn render(template: &str, globals: &dyn ObjectView) -> String {
+        let template = Template::new(liquid_core::parser::parse(template, &OPTIONS).unwrap());
+        let runtime = RuntimeBuilder::new().set_globals(globals).build();
+        template.render(&runtime).unwrap()
+    }
+
+    #[rstest]
+    #[case(
+        "{{ user.name }} is {{ user.age }}, {{ user.score }} {{ user.admin }} [{{ user.nothing }}]"
+    )]
+    #[case("{{ items[0] }} {{ items[-1] }} {{ items.first }} {{ items.last }} {{ items.size }}")]
+    #[case("{{ items | join: ',' }} {{ items | reverse | first }} {{ items | sort | last }}")]
+    #[case("{{ users | map: 'name' | join: ',' }} {{ users | where: 'admin', true | size }}")]
+    #[case("{{ users[0].name }}{{ users | size }}{{ user.size }}{{ user.name.size }}")]
+    #[case("{% for u in users %}{{ forloop.index }}={{ u.name | upcase }};{% endfor %}")]
+    #[case("{% if no_text == empty %}a{% endif %}{% if no_list == empty %}b{% endif %}{% if user.nothing == nil %}c{% endif %}{% unless user.nothing %}d{% endunless %}")]
+    #[case("{{ no_text | default: 'x' }}{{ no_list | default: 'y' }}{{ user.nothing | default: 'z' }}{{ user.admin | default: 'w' }}")]
+    #[case("{% if items contains 2 %}yes{% endif %}{% if user.name contains 'an' %}yes{% endif %}")]
+    #[case("{{ user.score | plus: 1 }} {{ user.age | times: 2.5 }} {{ whole_float }} {{ items | sum }}")]
+    #[case("{{ user | to_json }} {{ users | sort_by: 'name' | map: 'name' | join: ',' }}")]
+    #[case("{% assign n = user.age %}{{ n | minus: 1 }} {% case user.name %}{% when 'jane' %}jane{% endcase %}")]
+    fn matches_converted_values(#[case] template: &str) {
+        let data = json!({
+            "user": {"name": "jane", "age": 42, "score": 1.5, "admin": true, "nothing": null},
+            "items": [3, 1, 2],
+            "users": [{"name": "b", "admin": true}, {"name": "a", "admin": false}],
+            "no_text": "",
+            "no_list": [],
+            "whole_float": 2.0,
+        });
+        let converted = to_liquid_value(&data).unwrap();
+        let expected = render(template, converted.as_object().unwrap());
+        let actual = render(template, JsonObject::new(data.as_object().unwrap()));
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(json!({"n": u64::MAX}))]
+    #[case(json!({"a": [{"n": u64::MAX}]}))]
+    #[case(json!([1]))]
+    fn invalid(#[case] data: serde_json::Value) {
+        assert!(context(&data).is_err());
+    }
+}
@@ -30,13 +30,12 @@
     clippy::str_to_string,
     clippy::inherent_to_string,
     clippy::let_and_return,
-    clippy::string_to_string,
     clippy::try_err,
     clippy::unused_async,
     clippy::missing_enforced_import_renames,
     clippy::nonstandard_macro_braces,
     clippy::rc_mutex,
-    clippy::unwrap_or_else_default,
+    clippy::unwrap_or_default,
     clippy::manual_split_once,
     clippy::derivable_impls,
     clippy::needless_option_as_deref,
@@ -60,7 +59,6 @@
     overflowing_literals,
     path_statements,
     patterns_in_fns_without_body,
-    private_in_public,
     trivial_bounds,
     trivial_casts,
     trivial_numeric_casts,
@@ -80,24 +78,32 @@
 )]
 #![allow(clippy::derive_partial_eq_without_eq, clippy::box_default)]
 
+mod environment;
 mod error;
 mod filters;
+mod json_view;
 mod liquid_json;
 #[cfg(feature = "serde")]
 mod liquid_json_value;
+mod matching;
 mod options;
+mod partial;
+#[cfg(feature = "schemars")]
+mod schema;
 
-use std::sync::Arc;
+use std::{borrow::Cow, sync::Arc};
 
+pub use environment::{Delimiters, Environment};
 pub use error::Error;
 use liquid::{Parser, ValueView};
 use liquid_core::{
     model::ScalarCow,
-    runtime::{RuntimeBuilder, Variable},
+    runtime::{GlobalFrame, RuntimeBuilder, Variable},
     Language, Runtime,
 };
 #[cfg(feature = "serde")]
 pub use liquid_json_value::LiquidJsonValue;
+pub use matching::Mismatch;
 use once_cell::sync::Lazy;
 use serde_json::Number;
 
@@ -105,50 +111,89 @@ pub use crate::liquid_json::LiquidJson;
 
 use self::options::OptionsBuilder;
 
+/// Registers this library's filters on a [liquid::ParserBuilder] or an [OptionsBuilder], so
+/// `render_string` and `LiquidJson` templates support the same filters.
+macro_rules! with_filters {
+    ($builder:expr) => {{
+        let builder = $builder
+            .filter(filters::Each::new())
+            .filter(filters::Output)
+            .filter(filters::Base64Decode)
+            .filter(filters::Base64Encode)
+            .filter(filters::HexDecode)
+            .filter(filters::HexEncode)
+            .filter(filters::PercentDecode)
+            .filter(filters::PercentEncode)
+            .filter(filters::Sha256)
+            .filter(filters::Sha1)
+            .filter(filters::Md5)
+            .filter(filters::HmacSha256)
+            .filter(filters::ToJson)
+            .filter(filters::JsonEscape)
+            .filter(filters::JsonPath)
+            .filter(filters::Pointer)
+            .filter(filters::Merge)
+            .filter(filters::DeepMerge)
+            .filter(filters::Pick)
+            .filter(filters::Omit)
+            .filter(filters::Set)
+            .filter(filters::Keys)
+            .filter(filters::Values)
+            .filter(filters::Entries)
+            .filter(filters::FromEntries)
+            .filter(filters::UuidV4)
+            .filter(filters::UuidV5)
+            .filter(filters::RandomInt)
+            .filter(filters::Shuffle)
+            .filter(filters::Sample)
+            .filter(filters::Date::new())
+            .filter(filters::ParseDate)
+            .filter(filters::ToIso8601)
+            .filter(filters::ToUnix)
+            .filter(filters::AddDuration)
+            .filter(filters::InTimezone)
+            .filter(filters::RegexMatch)
+            .filter(filters::RegexReplace)
+            .filter(filters::RegexCaptures)
+            .filter(filters::SnakeCase)
+            .filter(filters::CamelCase)
+            .filter(filters::PascalCase)
+            .filter(filters::KebabCase)
+            .filter(filters::TitleCase)
+            .filter(filters::Slugify)
+            .filter(filters::ConvertKeys)
+            .filter(filters::Sum)
+            .filter(filters::Min)
+            .filter(filters::Max)
+            .filter(filters::Average)
+            .filter(filters::CountBy)
+            .filter(filters::GroupBy)
+            .filter(filters::IndexBy)
+            .filter(filters::SortBy)
+            .filter(filters::Flatten)
+            .filter(filters::Zip)
+            .filter(filters::Chunk)
+            .filter(filters::Range)
+            .filter(filters::Coalesce);
+        #[cfg(feature = "serde")]
+        let builder = builder.filter(filters::Json);
+        builder
+    }};
+}
+
 static PARSER: Lazy<Arc<Parser>> = Lazy::new(|| {
-    let builder = liquid::ParserBuilder::with_stdlib()
-        .filter(filters::Each::new())
-        .filter(filters::Output)
-        .filter(filters::Base64Decode)
-        .filter(filters::Base64Encode);
-    #[cfg(feature = "serde")]
-    let builder = builder.filter(filters::Json);
+    let builder = with_filters!(liquid::ParserBuilder::with_stdlib());
     Arc::new(builder.build().unwrap())
 });
 
-static OPTIONS: Lazy<Arc<Language>> = Lazy::new(|| {
-    let builder = OptionsBuilder::new()
-        .stdlib()
-        .filter(filters::Each::new())
-        .filter(filters::Output)
-        .filter(filters::Base64Decode)
-        .filter(filters::Base64Encode);
-    #[cfg(feature = "serde")]
-    let builder = builder.filter(filters::Json);
-    builder.build()
-});
+static OPTIONS: Lazy<Arc<Language>> =
+    Lazy::new(|| with_filters!(OptionsBuilder::new().stdlib()).build());
 
 /// Utility function to render a basic string with a [serde_json::Value] instead of dealing with [liquid::Object].
 pub fn render_string(template: &str, data: &serde_json::Value) -> Result<String, Error> {
     let template = PARSER.parse(template)?;
-    let data = to_liquid_obj(data)?;
-    Ok(template.render(&data)?)
-}
-
-fn to_liquid_obj(value: &serde_json::Value) -> Result<liquid::Object, Error> {
-    // let mut obj = liquid::Object::new();
-    match value {
-        serde_json::Value::Object(v) => v
-            .into_iter()
-            .map(|(k, v)| {
-                Ok((
-                    liquid::model::KString::from_string(k.clone()),
-                    to_liquid_value(v)?,
-                ))
-            })
-            .collect::<Result<liquid::Object, Error>>(),
-        _ => Err(Error::InvalidContext(value.clone())),
-    }
+    let data = json_view::context(data)?;
+    Ok(template.render(json_view::JsonObject::new(data))?)
 }
 
 fn to_liquid_value(value: &serde_json::Value) -> Result<liquid::model::Value, Error> {
@@ -190,8 +235,8 @@ fn to_liquid_value(value: &serde_json::Value) -> Result<liquid::model::Value, Er
     })
 }
 
-fn to_json_value(value: liquid::model::Value) -> serde_json::Value {
-    match value {
+fn to_json_value(value: liquid::model::Value) -> Result<serde_json::Value, liquid::Error> {
+    let value = match value {
         liquid::model::Value::Scalar(v) => {
             // have to match on type name because liquid::model::Scalar doesn't expose its enum.
             let name = v.type_name();
@@ -199,69 +244,205 @@ fn to_json_value(value: liquid::model::Value) -> serde_json::Value {
                 "string" => serde_json::Value::String(v.to_kstr().to_string()),
                 "whole number" => serde_json::Value::Number(Number::from(v.to_integer().unwrap())),
                 "fractional number" => {
-                    serde_json::Value::Number(Number::from_f64(v.to_float().unwrap()).unwrap())
+                    let float = v.to_float().unwrap();
+                    serde_json::Value::Number(Number::from_f64(float).ok_or_else(|| {
+                        liquid::Error::with_msg(format!("{} can't be represented in JSON", float))
+                    })?)
                 }
                 "boolean" => serde_json::Value::Bool(v.to_bool().unwrap()),
-                _ => panic!("Unknown scalar type: {}", name),
+                "date time" => {
+                    let date: time::OffsetDateTime = *v.to_date_time().unwrap();
+                    serde_json::Value::String(
+                        date.format(&time::format_description::well_known::Rfc3339)
+                            .map_err(|e| liquid::Error::with_msg(e.to_string()))?,
+                    )
+                }
+                "date" => serde_json::Value::String(v.to_date().unwrap().to_string()),
+                _ => {
+                    return Err(liquid::Error::with_msg(format!(
+                        "Unknown scalar type: {}",
+                        name
+                    )))
+                }
             }
         }
         liquid::model::Value::Array(v) => {
-            serde_json::Value::Array(v.into_iter().map(to_json_value).collect())
+            serde_json::Value::Array(v.into_iter().map(to_json_value).collect::<Result<_, _>>()?)
         }
         liquid::model::Value::Object(v) => serde_json::Value::Object(
             v.into_iter()
-                .map(|(k, v)| (k.to_string(), to_json_value(v)))
-                .collect(),
+                .map(|(k, v)| Ok((k.to_string(), to_json_value(v)?)))
+                .collect::<Result<_, liquid::Error>>()?,
         ),
-        liquid::model::Value::State(_v) => panic!("State not supported"),
+        // `empty` and `blank` render as nothing, the same as they do in text.
+        liquid::model::Value::State(_) => serde_json::Value::String(String::new()),
         liquid::model::Value::Nil => serde_json::Value::Null,
-    }
+    };
+    Ok(value)
+}
+
+/// Get the value marked by the `output` filter, if any.
+pub(crate) fn output_value(runtime: &dyn Runtime) -> Option<liquid::model::Value> {
+    let sentinel = Variable::with_literal("__output__");
+    let output = sentinel.try_evaluate(runtime)?;
+    runtime.try_get(&output).map(|value| value.to_value())
 }
 
 static SINGLE_VALUE: Lazy<regex::Regex> =
     Lazy::new(|| regex::Regex::new(r"^\{\{\s*(\w*)\s*\}\}$").unwrap());
 
+/// Filters that keep the type of their result when they end a string's only expression, the same
+/// way a plain `{{ value }}` does.
+const TYPED_FILTERS: [&str; 2] = ["coalesce", "default"];
+
+/// Keys that mark an object as a literal, whose only value is emitted without being rendered.
+const LITERAL_KEYS: [&str; 2] = ["$literal", "$raw"];
+
+/// The value a `{"$literal": ...}` object stands for, if the object is one.
+pub(crate) fn literal(
+    object: &serde_json::Map<String, serde_json::Value>,
+) -> Option<&serde_json::Value> {
+    match object.iter().next() {
+        Some((key, value)) if object.len() == 1 && LITERAL_KEYS.contains(&key.as_str()) => {
+            Some(value)
+        }
+        _ => None,
+    }
+}
+
+fn typed_template(template: &str) -> Cow<'_, str> {
+    match filters::single_expression(template) {
+        Some(expression)
+            if filters::last_filter(expression).is_some_and(|f| TYPED_FILTERS.contains(&f)) =>
+        {
+            Cow::Owned(format!("{{{{ {} | output }}}}", expression))
+        }
+        _ => Cow::Borrowed(template),
+    }
+}
+
+fn render_template(
+    template: &serde_json::Value,
+    data: &serde_json::Value,
+    env: &Environment,
+) -> Result<serde_json::Value, Error> {
+    let data = json_view::context(data)?;
+    let runtime = RuntimeBuilder::new()
+        .set_globals(json_view::JsonObject::new(data))
+        .build();
+    env.install(&runtime);
+    render_value(template, data, &runtime)
+}
+
+fn validate_template(template: &serde_json::Value, env: &Environment) -> Result<(), Error> {
+    let runtime = RuntimeBuilder::new().build();
+    env.install(&runtime);
+    validate_value(template, String::new(), &runtime)
+}
+
+/// Parses every string in a template, reporting where the first invalid one is.
+fn validate_value(
+    value: &serde_json::Value,
+    path: String,
+    runtime: &dyn Runtime,
+) -> Result<(), Error> {
+    match value {
+        serde_json::Value::String(s) => {
+            let s = runtime
+                .registers()
+                .get_mut::<environment::DelimiterRegister>()
+                .translate(s);
+            match liquid_core::parser::parse(&typed_template(&s), &OPTIONS) {
+                Ok(_) => Ok(()),
+                Err(source) => Err(Error::InvalidTemplate { path, source }),
+            }
+        }
+        serde_json::Value::Array(a) => a
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, v)| validate_value(v, format!("{}/{}", path, i), runtime)),
+        serde_json::Value::Object(o) if literal(o).is_some() => Ok(()),
+        serde_json::Value::Object(o) => o.iter().try_for_each(|(k, v)| {
+            validate_value(
+                v,
+                format!("{}/{}", path, matching::escape_pointer(k)),
+                runtime,
+            )
+        }),
+        _ => Ok(()),
+    }
+}
+
 fn render_value(
     value: &serde_json::Value,
-    data: &liquid::Object,
+    data: &serde_json::Map<String, serde_json::Value>,
+    runtime: &dyn Runtime,
 ) -> Result<serde_json::Value, Error> {
     match value {
         serde_json::Value::String(s) => {
+            let s = runtime
+                .registers()
+                .get_mut::<environment::DelimiterRegister>()
+                .translate(s);
             // Special case: if the entire string is a single value, return that JSON value directly.
-            if let Some(cap) = SINGLE_VALUE.captures(s) {
+            if let Some(cap) = SINGLE_VALUE.captures(&s) {
                 let key = cap.get(1).unwrap().as_str();
                 if let Some(val) = data.get(key) {
-                    return Ok(to_json_value(val.clone()));
+                    return Ok(val.clone());
                 }
             }
             let mut output = Vec::new();
-            let runtime = RuntimeBuilder::new().set_globals(data).build();
+            // Every string shares the render's runtime, but gets its own globals so values set by
+            // `assign` or `output` don't leak into other strings.
+            let runtime = GlobalFrame::new(runtime);
 
-            let elements = liquid_core::parser::parse(s, &OPTIONS)?;
+            let elements = liquid_core::parser::parse(&typed_template(&s), &OPTIONS)?;
             for element in elements {
                 element.render_to(&mut output, &runtime)?;
             }
-            let sentinel = Variable::with_literal("__output__");
-            if let Some(output) = sentinel.try_evaluate(&runtime) {
-                if let Some(value) = runtime.try_get(&output) {
-                    return Ok(to_json_value(value.to_value()));
-                }
+            if let Some(value) = output_value(&runtime) {
+                return Ok(to_json_value(value)?);
             }
             let output = String::from_utf8(output).unwrap();
             Ok(serde_json::Value::String(output))
         }
         serde_json::Value::Array(a) => Ok(serde_json::Value::Array(
             a.iter()
-                .map(|v| render_value(v, data))
+                .map(|v| render_value(v, data, runtime))
                 .collect::<Result<Vec<serde_json::Value>, _>>()?,
         )),
         serde_json::Value::Object(o) => {
+            if let Some(value) = literal(o) {
+                return Ok(value.clone());
+            }
             let map = o
                 .into_iter()
-                .map(|(k, v)| Ok((k.clone(), render_value(v, data)?)))
+                .map(|(k, v)| Ok((k.clone(), render_value(v, data, runtime)?)))
                 .collect::<Result<serde_json::Map<String, serde_json::Value>, Error>>()?;
             Ok(serde_json::Value::Object(map))
         }
         _ => Ok(value.clone()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_json::json;
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(
+        "{{ 'hello' | sha256 }}",
+        "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+    )]
+    #[case("{{ name | slugify }}", "hello-world")]
+    #[case("{{ nums | sum }}", "6")]
+    #[case("{% for n in nums %}{{ n }}{% endfor %}", "123")]
+    fn render_string(#[case] template: &str, #[case] expected: &str) -> Result<()> {
+        let data = json!({"name": "Hello, World!", "nums": [1, 2, 3]});
+        assert_eq!(super::render_string(template, &data)?, expected);
+        Ok(())
+    }
+}
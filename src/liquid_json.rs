@@ -1,4 +1,7 @@
-use crate::{render_value, to_liquid_obj, Error};
+use crate::{
+    matching::extract, partial::render_partial, render_template, validate_template, Environment,
+    Error,
+};
 
 /// A JSON structured Liquid template.
 #[must_use]
@@ -29,11 +32,74 @@ impl LiquidJson {
 
     /// Render the Liquid JSON template with the given data.
     pub fn render(&self, data: &serde_json::Value) -> Result<serde_json::Value, Error> {
-        let data = to_liquid_obj(data)?;
-        render_value(&self.raw_template, &data)
+        self.render_with(data, &Environment::default())
+    }
+
+    /// Render the Liquid JSON template with the given data and [Environment].
+    pub fn render_with(
+        &self,
+        data: &serde_json::Value,
+        env: &Environment,
+    ) -> Result<serde_json::Value, Error> {
+        render_template(&self.raw_template, data, env)
+    }
+
+    /// Check that every string in the template is valid Liquid, without rendering it.
+    pub fn validate(&self) -> Result<(), Error> {
+        self.validate_with(&Environment::default())
+    }
+
+    /// Check that every string in the template is valid Liquid with the delimiters of the given
+    /// [Environment].
+    pub fn validate_with(&self, env: &Environment) -> Result<(), Error> {
+        validate_template(&self.raw_template, env)
+    }
+
+    /// Render only the expressions whose variables are all in `data`, leaving the rest of the
+    /// template in place so the result can be rendered again later with the remaining data.
+    ///
+    /// Expressions that don't use any variables (e.g. `{{ 'now' | to_iso8601 }}`) are left for
+    /// the final render, and strings with tags like `{% if %}` are only rendered once every
+    /// variable they use is available. An expression that uses variables from both stages is
+    /// left whole, so the later render needs all of its variables.
+    pub fn render_partial(&self, data: &serde_json::Value) -> Result<LiquidJson, Error> {
+        self.render_partial_with(data, &Environment::default())
+    }
+
+    /// Render only the expressions whose variables are all in `data` with the given
+    /// [Environment]. Markup that's left in place keeps the environment's delimiters, so later
+    /// renders should use the same environment.
+    pub fn render_partial_with(
+        &self,
+        data: &serde_json::Value,
+        env: &Environment,
+    ) -> Result<LiquidJson, Error> {
+        render_partial(&self.raw_template, data, env).map(LiquidJson::new)
+    }
+
+    /// Recover the variables that would render this template into `document`, e.g. matching
+    /// `{"id": "{{ user_id }}"}` against `{"id": 42}` produces `{"user_id": 42}`.
+    ///
+    /// Plain `{{ variable }}` strings bind the matching value whatever its type, and strings that
+    /// interpolate variables into text bind the matching text. Strings with filters or tags can't
+    /// be reversed, so they're checked by rendering them with the recovered variables instead.
+    /// Every place the document doesn't match is reported in [Error::Mismatch].
+    pub fn extract(&self, document: &serde_json::Value) -> Result<serde_json::Value, Error> {
+        self.extract_with(document, &Environment::default())
+    }
+
+    /// Recover the variables that would render this template into `document` with the given
+    /// [Environment], e.g. to match a template that uses custom delimiters.
+    pub fn extract_with(
+        &self,
+        document: &serde_json::Value,
+        env: &Environment,
+    ) -> Result<serde_json::Value, Error> {
+        extract(&self.raw_template, document, env)
     }
 
     /// Get the inner [serde_json::Value] value.
+    #[must_use]
     pub fn as_json(&self) -> &serde_json::Value {
         &self.raw_template
     }
@@ -58,10 +124,67 @@ mod tests {
     #[case(json!({"this":"{{ myval }}"}), json!({"myval": [5.1,4.2]}), json!({"this":[5.1,4.2]}))]
     #[case(json!({"this":"{{ myval | each: \"my num: {{el}}\" | output}}"}), json!({"myval": [5.1,4.2]}), json!({"this":["my num: 5.1","my num: 4.2"]}))]
     #[case(json!({"this":"{{ myval | default: 'hey'}}"}), json!({}), json!({"this":"hey"}))]
+    #[case(json!({"this":"{{ retries | default: 3 }}"}), json!({}), json!({"this":3}))]
+    #[case(json!({"this":"{{ retries | default: 3 }}"}), json!({"retries": 5}), json!({"this":5}))]
+    #[case(json!({"this":"{{ x | default: empty }}"}), json!({}), json!({"this":""}))]
+    #[case(json!({"this":"{{ x | default: blank }}"}), json!({}), json!({"this":""}))]
+    #[case(json!({"this":"{{ tags | default: fallback }}"}), json!({"fallback": ["a"]}), json!({"this":["a"]}))]
+    #[case(json!({"this":"retries={{ retries | default: 3 }}"}), json!({}), json!({"this":"retries=3"}))]
+    #[case(json!({"this":{"$literal": "{{ myval }}"}}), json!({"myval": 5}), json!({"this":"{{ myval }}"}))]
+    #[case(json!({"this":{"$raw": {"a": ["{% if %}"]}}}), json!({}), json!({"this":{"a": ["{% if %}"]}}))]
+    #[case(json!({"this":{"$literal": {"$literal": 1}}}), json!({}), json!({"this":{"$literal": 1}}))]
+    #[case(json!({"this":{"$literal": "{{ a }}", "b": "{{ myval }}"}}), json!({"myval": 5, "a": 1}), json!({"this":{"$literal": 1, "b": 5}}))]
     fn basic(#[case] template: Value, #[case] data: Value, #[case] expected: Value) -> Result<()> {
         let tmpl = LiquidJson::new(template);
         let actual = tmpl.render(&data)?;
         assert_eq!(actual, expected);
         Ok(())
     }
+
+    #[rstest]
+    #[case(json!({"n": "[[ n ]]", "s": "n=[[ n ]]"}), json!({"n": 5}), json!({"n": 5, "s": "n=5"}))]
+    #[case(json!({"this": "{{ .Values.name }}-[[ name ]]"}), json!({"name": "api"}), json!({"this": "{{ .Values.name }}-api"}))]
+    #[case(json!({"this": "[% if on %]yes[% else %]no[% endif %]"}), json!({"on": true}), json!({"this": "yes"}))]
+    #[case(json!({"this": "[[ 'a]]b' | upcase ]]"}), json!({}), json!({"this": "A]]B"}))]
+    #[case(json!({"this": "[% raw %][[ x ]][% endraw %]"}), json!({"x": 1}), json!({"this": "[[ x ]]"}))]
+    #[case(json!({"this": "[[ items | each: '[[ el | times: 2 ]]' | output ]]"}), json!({"items": [1, 2]}), json!({"this": [2, 4]}))]
+    #[case(json!({"this": "[[ retries | default: 3 ]]"}), json!({}), json!({"this": 3}))]
+    #[case(json!({"this": "[[ unterminated"}), json!({}), json!({"this": "[[ unterminated"}))]
+    #[case(json!({"this": "{% endraw %} [[ a ]]"}), json!({"a": 1}), json!({"this": "{% endraw %} 1"}))]
+    #[case(json!({"this": "{%- endraw -%}{{ [% raw %]{%endraw%}{{[% endraw %]"}), json!({}), json!({"this": "{%- endraw -%}{{ {%endraw%}{{"}))]
+    fn delimiters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let env =
+            Environment::new().with_delimiters(crate::Delimiters::new(("[[", "]]"), ("[%", "%]")));
+        let actual = LiquidJson::new(template).render_with(&data, &env)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn dollar_delimiters() -> Result<()> {
+        let env = Environment::new()
+            .with_delimiters(crate::Delimiters::new(("${{", "}}"), ("${%", "%}")));
+        let tmpl =
+            LiquidJson::new(json!({"run": "echo ${{ github.sha }} {{ id }}", "id": "${{ id }}"}));
+        let actual = tmpl.render_with(&json!({"github": {"sha": "abc"}, "id": 7}), &env)?;
+        assert_eq!(actual, json!({"run": "echo abc {{ id }}", "id": 7}));
+        Ok(())
+    }
+
+    #[test]
+    fn validate() {
+        let tmpl = LiquidJson::new(json!({"image": "{{ .Values.registry }}/[[ name ]]"}));
+        assert!(tmpl.validate().is_err());
+        let env =
+            Environment::new().with_delimiters(crate::Delimiters::new(("[[", "]]"), ("[%", "%]")));
+        assert!(tmpl.validate_with(&env).is_ok());
+        let tmpl = LiquidJson::new(json!({"image": "[[ name | no_such_filter ]]"}));
+        assert!(
+            matches!(tmpl.validate_with(&env), Err(Error::InvalidTemplate { path, .. }) if path == "/image")
+        );
+    }
 }
@@ -1,22 +1,20 @@
-use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
 
-use crate::{liquid_json::LiquidJson, Error};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{liquid_json::LiquidJson, Environment, Error};
 
-#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 /// A Liquid JSON value that implements Serialize/Deserialize.
+///
+/// The type parameter is what the template renders into, e.g. a `LiquidJsonValue<u16>` field
+/// renders to a port number. It defaults to [serde_json::Value].
 #[must_use]
-pub struct LiquidJsonValue(
-    #[serde(serialize_with = "ser_with", deserialize_with = "deser_with")] LiquidJson,
-);
+pub struct LiquidJsonValue<T = serde_json::Value>(LiquidJson, PhantomData<fn() -> T>);
 
-impl LiquidJsonValue {
+impl<T> LiquidJsonValue<T> {
     /// Create a new Liquid JSON value from a JSON value.
     pub fn new(raw_template: serde_json::Value) -> Self {
-        LiquidJsonValue(LiquidJson::new(raw_template))
-    }
-    /// Render the JSON template with the given data.
-    pub fn render(&self, data: &serde_json::Value) -> Result<serde_json::Value, Error> {
-        self.0.render(data)
+        LiquidJsonValue(LiquidJson::new(raw_template), PhantomData)
     }
 
     /// Get the inner [LiquidJson] value.
@@ -25,31 +23,69 @@ impl LiquidJsonValue {
     }
 
     /// Get the unrendered template as a [serde_json::Value].
+    #[must_use]
     pub fn as_json(&self) -> &serde_json::Value {
         self.0.as_json()
     }
 }
 
-impl From<serde_json::Value> for LiquidJsonValue {
+impl<T: DeserializeOwned> LiquidJsonValue<T> {
+    /// Render the JSON template with the given data.
+    pub fn render(&self, data: &serde_json::Value) -> Result<T, Error> {
+        self.render_with(data, &Environment::default())
+    }
+
+    /// Render the JSON template with the given data and [Environment].
+    pub fn render_with(&self, data: &serde_json::Value, env: &Environment) -> Result<T, Error> {
+        let value = self.0.render_with(data, env)?;
+        serde_json::from_value(value).map_err(Error::Deserialize)
+    }
+}
+
+impl<T> From<serde_json::Value> for LiquidJsonValue<T> {
     fn from(value: serde_json::Value) -> Self {
         LiquidJsonValue::new(value)
     }
 }
 
-fn ser_with<S>(tpl: &LiquidJson, s: S) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    tpl.raw_template.serialize(s)
+impl<T> Clone for LiquidJsonValue<T> {
+    fn clone(&self) -> Self {
+        LiquidJsonValue(self.0.clone(), PhantomData)
+    }
 }
 
-fn deser_with<'de, D>(deserializer: D) -> Result<LiquidJson, D::Error>
-where
-    D: serde::de::Deserializer<'de>,
-{
-    let raw_template = serde_json::Value::deserialize(deserializer)?;
-    Ok(LiquidJson::new(raw_template))
+impl<T> std::fmt::Debug for LiquidJsonValue<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LiquidJsonValue").field(&self.0).finish()
+    }
+}
+
+impl<T> PartialEq for LiquidJsonValue<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
 }
+
+impl<T> Serialize for LiquidJsonValue<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.raw_template.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for LiquidJsonValue<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let template = Self::new(serde_json::Value::deserialize(deserializer)?);
+        template.0.validate().map_err(serde::de::Error::custom)?;
+        Ok(template)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ::serde::{Deserialize, Serialize};
@@ -76,4 +112,62 @@ mod tests {
         assert_eq!(to_json, from_json);
         Ok(())
     }
+
+    #[rstest]
+    #[case(json!({"inner_liquid":"{{ myval"}), "\"\"")]
+    #[case(json!({"inner_liquid":{"key":["ok", "{% if %}"]}}), "\"/key/1\"")]
+    #[case(json!({"inner_liquid":{"a/b":"{{ x | no_such_filter }}"}}), "\"/a~1b\"")]
+    fn invalid(#[case] from_json: Value, #[case] path: &str) {
+        let err = serde_json::from_value::<TestSerde>(from_json).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains(&format!("Invalid template at {}", path)),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn literals_are_not_validated() -> Result<()> {
+        let from_json = json!({"inner_liquid":{"$literal": "{{ myval"}});
+        let deser: TestSerde = serde_json::from_value(from_json)?;
+        assert_eq!(deser.inner_liquid.render(&json!({}))?, json!("{{ myval"));
+        Ok(())
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Server {
+        host: String,
+        port: u16,
+    }
+
+    #[derive(Deserialize)]
+    struct TypedSerde {
+        port: LiquidJsonValue<u16>,
+        server: LiquidJsonValue<Server>,
+        hosts: LiquidJsonValue<Vec<String>>,
+    }
+
+    #[test]
+    fn typed() -> Result<()> {
+        let config: TypedSerde = serde_json::from_value(json!({
+            "port": "{{ port }}",
+            "server": {"host": "{{ host }}", "port": "{{ port }}"},
+            "hosts": "{{ host | split: ',' | output }}",
+        }))?;
+        let data = json!({"host": "db", "port": 5432});
+        assert_eq!(config.port.render(&data)?, 5432);
+        assert_eq!(
+            config.server.render(&data)?,
+            Server {
+                host: "db".to_owned(),
+                port: 5432
+            }
+        );
+        assert_eq!(config.hosts.render(&data)?, vec!["db"]);
+
+        let err = config.port.render(&json!({"port": "high"})).unwrap_err();
+        assert!(matches!(err, Error::Deserialize(_)), "{}", err);
+        Ok(())
+    }
 }
@@ -0,0 +1,327 @@
+use std::collections::HashSet;
+
+use once_cell::sync::Lazy;
+use serde_json::{Map, Value};
+
+use crate::partial::{segments, Segment};
+use crate::{literal, render_template, Delimiters, Environment, Error, SINGLE_VALUE};
+
+static VARIABLE: Lazy<regex::Regex> =
+    Lazy::new(|| regex::Regex::new(r"^[A-Za-z_][\w-]*(\.[A-Za-z_][\w-]*)*$").unwrap());
+
+/// A place where a document couldn't have been rendered from a template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// The [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901) to the mismatched value in the
+    /// document.
+    pub path: String,
+    /// Why the value doesn't match.
+    pub message: String,
+}
+
+impl std::fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+struct Matcher<'t> {
+    env: &'t Environment,
+    bindings: Map<String, Value>,
+    mismatches: Vec<Mismatch>,
+    /// Variables only bound from text so far, which a typed value that renders to the same text
+    /// can replace.
+    text_bindings: HashSet<String>,
+    /// Strings that can't be reversed, to check by rendering them once every binding is known.
+    deferred: Vec<(String, &'t str, &'t Value)>,
+}
+
+pub(crate) fn extract(
+    template: &Value,
+    document: &Value,
+    env: &Environment,
+) -> Result<Value, Error> {
+    let mut matcher = Matcher {
+        env,
+        bindings: Map::new(),
+        mismatches: Vec::new(),
+        text_bindings: HashSet::new(),
+        deferred: Vec::new(),
+    };
+    matcher.match_value(String::new(), template, document);
+    let bindings = Value::Object(std::mem::take(&mut matcher.bindings));
+    for (path, template, document) in std::mem::take(&mut matcher.deferred) {
+        let template = Value::String(template.to_owned());
+        match render_template(&template, &bindings, env) {
+            Ok(rendered) if &rendered == document => {}
+            Ok(rendered) => {
+                matcher.mismatch(path, format!("expected {}, got {}", rendered, document));
+            }
+            Err(e) => matcher.mismatch(path, format!("couldn't render {}: {}", template, e)),
+        }
+    }
+    if matcher.mismatches.is_empty() {
+        Ok(bindings)
+    } else {
+        Err(Error::Mismatch(matcher.mismatches))
+    }
+}
+
+impl<'t> Matcher<'t> {
+    fn mismatch(&mut self, path: String, message: String) {
+        self.mismatches.push(Mismatch { path, message });
+    }
+
+    fn match_value(&mut self, path: String, template: &'t Value, document: &'t Value) {
+        // A literal renders to exactly the value it encloses, even if it looks like a template.
+        if let Some(literal) = template.as_object().and_then(literal) {
+            if literal != document {
+                self.mismatch(path, format!("expected {}, got {}", literal, document));
+            }
+            return;
+        }
+        match (template, document) {
+            (Value::String(s), _) => self.match_string(path, s, document),
+            (Value::Array(template), Value::Array(document)) => {
+                if template.len() != document.len() {
+                    let message = format!(
+                        "expected {} elements, got {}",
+                        template.len(),
+                        document.len()
+                    );
+                    return self.mismatch(path, message);
+                }
+                for (i, (template, document)) in template.iter().zip(document).enumerate() {
+                    self.match_value(format!("{}/{}", path, i), template, document);
+                }
+            }
+            (Value::Object(template), Value::Object(document)) => {
+                for (key, template) in template {
+                    let path = format!("{}/{}", path, escape_pointer(key));
+                    match document.get(key) {
+                        Some(document) => self.match_value(path, template, document),
+                        None => self.mismatch(path, "missing".to_owned()),
+                    }
+                }
+                for key in document.keys().filter(|k| !template.contains_key(*k)) {
+                    let path = format!("{}/{}", path, escape_pointer(key));
+                    self.mismatch(path, "unexpected key".to_owned());
+                }
+            }
+            _ if template == document => {}
+            _ => self.mismatch(path, format!("expected {}, got {}", template, document)),
+        }
+    }
+
+    fn match_string(&mut self, path: String, template: &'t str, document: &'t Value) {
+        let translated = self.env.translate(template);
+        let segments = segments(&translated, &Delimiters::default());
+        let reversible = segments.iter().all(|segment| match segment {
+            Segment::Text(text) => !text.contains("{%"),
+            Segment::Output {
+                expression,
+                trim_before,
+                trim_after,
+                ..
+            } => {
+                !trim_before
+                    && !trim_after
+                    && (VARIABLE.is_match(expression) || string_literal(expression).is_some())
+            }
+            Segment::Tag { .. } => false,
+        });
+        if !reversible {
+            return self.deferred.push((path, template, document));
+        }
+
+        // A plain `{{ variable }}` renders to the variable's value, whatever its type. Other
+        // names, like `my-var`, render to text.
+        if let [Segment::Output { expression, .. }] = segments.as_slice() {
+            if SINGLE_VALUE.is_match(&translated) {
+                return self.bind(&path, expression, document.clone(), false);
+            }
+        }
+
+        let Value::String(document) = document else {
+            return self.mismatch(path, format!("expected a string, got {}", document));
+        };
+        let mut pattern = String::from("^");
+        for segment in &segments {
+            match segment {
+                Segment::Text(text) => pattern.push_str(&regex::escape(text)),
+                Segment::Output { expression, .. } => match string_literal(expression) {
+                    Some(text) => pattern.push_str(&regex::escape(text)),
+                    None => pattern.push_str("((?s:.*?))"),
+                },
+                Segment::Tag { .. } => unreachable!("strings with tags aren't reversible"),
+            }
+        }
+        pattern.push('$');
+        let captures = regex::Regex::new(&pattern)
+            .ok()
+            .and_then(|regex| regex.captures(document));
+        let Some(captures) = captures else {
+            return self.mismatch(path, format!("{:?} doesn't match {:?}", document, template));
+        };
+        let variables = segments.iter().filter_map(|segment| match segment {
+            Segment::Output { expression, .. } if string_literal(expression).is_none() => {
+                Some(*expression)
+            }
+            _ => None,
+        });
+        for (variable, capture) in variables.zip(captures.iter().skip(1)) {
+            let value = capture.map_or("", |c| c.as_str());
+            self.bind(&path, variable, Value::String(value.to_owned()), true);
+        }
+    }
+
+    /// Binds a variable, or a property path like `user.id`, reporting a mismatch if it's already
+    /// bound to a different value. Values matched from text agree with any value that renders to
+    /// the same text.
+    fn bind(&mut self, path: &str, variable: &str, value: Value, text: bool) {
+        let mut keys = variable.split('.').peekable();
+        let mut object = &mut self.bindings;
+        while let Some(key) = keys.next() {
+            if keys.peek().is_none() {
+                let existing_text = self.text_bindings.contains(variable);
+                match object.get(key) {
+                    Some(existing) if existing == &value => {}
+                    Some(existing) if text && as_text(existing) == as_text(&value) => {}
+                    Some(existing) if existing_text && as_text(existing) == as_text(&value) => {
+                        self.text_bindings.remove(variable);
+                        object.insert(key.to_owned(), value);
+                    }
+                    Some(existing) => {
+                        let message = format!(
+                            "`{}` is {} here but {} elsewhere",
+                            variable, value, existing
+                        );
+                        self.mismatches.push(Mismatch {
+                            path: path.to_owned(),
+                            message,
+                        });
+                    }
+                    None => {
+                        if text {
+                            self.text_bindings.insert(variable.to_owned());
+                        }
+                        object.insert(key.to_owned(), value);
+                    }
+                }
+                return;
+            }
+            let child = object
+                .entry(key)
+                .or_insert_with(|| Value::Object(Map::new()));
+            let Value::Object(child) = child else {
+                let message = format!("`{}` is bound to a value without properties", key);
+                self.mismatches.push(Mismatch {
+                    path: path.to_owned(),
+                    message,
+                });
+                return;
+            };
+            object = child;
+        }
+    }
+}
+
+/// The text of a quoted string like `'{{'`, which is how markup in literal text is escaped when
+/// the template uses custom delimiters.
+fn string_literal(expression: &str) -> Option<&str> {
+    let quote = expression
+        .chars()
+        .next()
+        .filter(|c| *c == '\'' || *c == '"')?;
+    let text = expression.strip_prefix(quote)?.strip_suffix(quote)?;
+    (!text.contains(quote)).then_some(text)
+}
+
+/// The text a scalar renders to, if it is one.
+fn as_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Null => Some(String::new()),
+        Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+pub(crate) fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Delimiters, Environment, Error, LiquidJson};
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"id": "{{ user_id }}"}), json!({"id": 42}), json!({"user_id": 42}))]
+    #[case(json!({"user": "{{ user }}", "n": 1}), json!({"user": {"name": "jane"}, "n": 1}), json!({"user": {"name": "jane"}}))]
+    #[case(json!({"url": "https://{{ host }}/users/{{ id }}"}), json!({"url": "https://example.com/users/42"}), json!({"host": "example.com", "id": "42"}))]
+    #[case(json!({"name": "{{ user.first }} {{ user.last }}"}), json!({"name": "Jane Doe"}), json!({"user": {"first": "Jane", "last": "Doe"}}))]
+    #[case(json!(["{{ a }}", "{{ a }}", {"b": "{{ b }}"}]), json!([1, 1, {"b": null}]), json!({"a": 1, "b": null}))]
+    #[case(json!({"id": "{{ id }}", "label": "item-{{ id }}"}), json!({"id": "7", "label": "item-7"}), json!({"id": "7"}))]
+    #[case(json!({"id": "{{ id }}", "upper": "{{ id | upcase }}"}), json!({"id": "ab", "upper": "AB"}), json!({"id": "ab"}))]
+    #[case(json!({"id": "{{ id }}", "static": "{{ 'x' | upcase }}", "raw": "{% raw %}{{ id }}{% endraw %}"}), json!({"id": 1, "static": "X", "raw": "{{ id }}"}), json!({"id": 1}))]
+    #[case(json!({"n": 1, "s": "text"}), json!({"n": 1, "s": "text"}), json!({}))]
+    #[case(json!({"url": "/users/{{ id }}", "id": "{{ id }}"}), json!({"url": "/users/42", "id": 42}), json!({"id": 42}))]
+    #[case(json!({"id": "{{ id }}", "url": "/users/{{ id }}"}), json!({"id": true, "url": "/users/true"}), json!({"id": true}))]
+    #[case(json!({"id": "{{ id }}", "docs": {"$literal": "{{ id }}"}}), json!({"id": 1, "docs": "{{ id }}"}), json!({"id": 1}))]
+    #[case(json!({"a": "{{ my-var }}"}), json!({"a": "5"}), json!({"my-var": "5"}))]
+    fn extract(
+        #[case] template: Value,
+        #[case] document: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let bindings = tmpl.extract(&document)?;
+        assert_eq!(bindings, expected);
+        assert_eq!(tmpl.render(&bindings)?, document);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!({"id": "{{ id }}", "n": 1}), json!({"id": 1, "n": 2}), vec!["/n"])]
+    #[case(json!({"a": "{{ v }}", "b": "{{ v }}"}), json!({"a": 1, "b": 2}), vec!["/b"])]
+    #[case(json!({"url": "https://{{ host }}/"}), json!({"url": "http://example.com/"}), vec!["/url"])]
+    #[case(json!({"url": "https://{{ host }}/"}), json!({"url": 1}), vec!["/url"])]
+    #[case(json!({"a/b": [1, "{{ x }}"]}), json!({"a/b": [1]}), vec!["/a~1b"])]
+    #[case(json!({"a": 1, "b": 2}), json!({"a": 1, "c": 3}), vec!["/b", "/c"])]
+    #[case(json!({"id": "{{ id }}", "upper": "{{ id | upcase }}"}), json!({"id": "ab", "upper": "ab"}), vec!["/upper"])]
+    #[case(json!({"upper": "{{ missing | append: other }}"}), json!({"upper": "x"}), vec!["/upper"])]
+    #[case(json!({"a": "{{ user }}", "b": "{{ user.id }}"}), json!({"a": 1, "b": "2"}), vec!["/b"])]
+    #[case(json!({"url": "/users/{{ id }}", "id": "{{ id }}"}), json!({"url": "/users/42", "id": 43}), vec!["/url"])]
+    #[case(json!({"docs": {"$literal": {"a": "{{ x }}"}}}), json!({"docs": {"a": "y"}}), vec!["/docs"])]
+    #[case(json!({"a": "{{ my-var }}"}), json!({"a": 5}), vec!["/a"])]
+    fn mismatches(#[case] template: Value, #[case] document: Value, #[case] expected: Vec<&str>) {
+        let tmpl = LiquidJson::new(template);
+        match tmpl.extract(&document) {
+            Err(Error::Mismatch(mismatches)) => {
+                let paths: Vec<_> = mismatches.iter().map(|m| m.path.as_str()).collect();
+                assert_eq!(paths, expected);
+            }
+            other => panic!("expected mismatches, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn delimiters() -> Result<()> {
+        let env = Environment::new().with_delimiters(Delimiters::new(("[[", "]]"), ("[%", "%]")));
+        let tmpl = LiquidJson::new(json!({
+            "id": "[[ id ]]",
+            "image": "{{ .Values.registry }}/[[ name ]]",
+            "upper": "[[ name | upcase ]]"
+        }));
+        let document = json!({"id": 7, "image": "{{ .Values.registry }}/api", "upper": "API"});
+        let bindings = tmpl.extract_with(&document, &env)?;
+        assert_eq!(bindings, json!({"id": 7, "name": "api"}));
+        assert_eq!(tmpl.render_with(&bindings, &env)?, document);
+        Ok(())
+    }
+}
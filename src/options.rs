@@ -117,7 +117,7 @@ impl OptionsBuilder {
             filters,
         } = self;
 
-        let mut options = parser::Language::empty();
+        let mut options = Language::empty();
         options.blocks = blocks;
         options.tags = tags;
         options.filters = filters;
@@ -0,0 +1,387 @@
+use std::borrow::Cow;
+
+use liquid_core::runtime::{GlobalFrame, RuntimeBuilder};
+use liquid_core::{Renderable, Runtime, Template};
+
+use crate::environment::markup_len;
+use crate::json_view::{context, JsonObject};
+use crate::{literal, render_value, Delimiters, Environment, Error, OPTIONS};
+
+/// Words that can appear in expressions and tags without being variables.
+const KEYWORDS: [&str; 10] = [
+    "true", "false", "nil", "null", "empty", "blank", "and", "or", "contains", "in",
+];
+
+/// A piece of a template string.
+#[derive(Debug)]
+pub(crate) enum Segment<'s> {
+    /// Plain text, including `{% raw %}` blocks.
+    Text(&'s str),
+    /// A `{{ expression }}`.
+    Output {
+        raw: &'s str,
+        expression: &'s str,
+        trim_before: bool,
+        trim_after: bool,
+    },
+    /// A `{% tag %}`.
+    Tag { raw: &'s str, body: &'s str },
+}
+
+pub(crate) fn render_partial(
+    template: &serde_json::Value,
+    data: &serde_json::Value,
+    env: &Environment,
+) -> Result<serde_json::Value, Error> {
+    let data = context(data)?;
+    let runtime = RuntimeBuilder::new()
+        .set_globals(JsonObject::new(data))
+        .build();
+    env.install(&runtime);
+    partial_value(template, data, &runtime, &env.delimiters())
+}
+
+fn partial_value(
+    value: &serde_json::Value,
+    data: &serde_json::Map<String, serde_json::Value>,
+    runtime: &dyn Runtime,
+    delimiters: &Delimiters,
+) -> Result<serde_json::Value, Error> {
+    match value {
+        serde_json::Value::String(s) => partial_string(s, data, runtime, delimiters),
+        serde_json::Value::Array(a) => Ok(serde_json::Value::Array(
+            a.iter()
+                .map(|v| partial_value(v, data, runtime, delimiters))
+                .collect::<Result<Vec<serde_json::Value>, _>>()?,
+        )),
+        // Literals are kept as they are so the final render still emits them verbatim.
+        serde_json::Value::Object(o) if literal(o).is_some() => Ok(value.clone()),
+        serde_json::Value::Object(o) => {
+            let map = o
+                .into_iter()
+                .map(|(k, v)| Ok((k.clone(), partial_value(v, data, runtime, delimiters)?)))
+                .collect::<Result<serde_json::Map<String, serde_json::Value>, Error>>()?;
+            Ok(serde_json::Value::Object(map))
+        }
+        _ => Ok(value.clone()),
+    }
+}
+
+fn partial_string(
+    s: &str,
+    data: &serde_json::Map<String, serde_json::Value>,
+    runtime: &dyn Runtime,
+    delimiters: &Delimiters,
+) -> Result<serde_json::Value, Error> {
+    let segments = segments(s, delimiters);
+    // Markup without variables, like `{{ 'now' | to_iso8601 }}`, is left for the final render.
+    let resolved = |variables: Vec<&str>| {
+        !variables.is_empty() && variables.iter().all(|v| data.contains_key(*v))
+    };
+
+    // Tags can depend on each other (e.g. `assign` or `for`), so strings with tags are rendered
+    // only when everything they use is available.
+    let has_tags = segments.iter().any(|s| matches!(s, Segment::Tag { .. }));
+    if has_tags {
+        let markup = segments
+            .iter()
+            .flat_map(|segment| match segment {
+                Segment::Text(_) => Vec::new(),
+                Segment::Output { expression, .. } => variables(expression),
+                Segment::Tag { body, .. } => variables(tag_arguments(body)),
+            })
+            .collect();
+        if resolved(markup) {
+            let value = render_value(&serde_json::Value::String(s.to_owned()), data, runtime)?;
+            return Ok(escape_markup(value, delimiters));
+        }
+        return Ok(serde_json::Value::String(s.to_owned()));
+    }
+
+    // A string that is a single expression renders like it would in a full render, keeping the
+    // type of its value.
+    if let [Segment::Output { expression, .. }] = segments.as_slice() {
+        if resolved(variables(expression)) {
+            let value = render_value(&serde_json::Value::String(s.to_owned()), data, runtime)?;
+            return Ok(escape_markup(value, delimiters));
+        }
+        return Ok(serde_json::Value::String(s.to_owned()));
+    }
+
+    let mut output = String::new();
+    let mut trim_next = false;
+    for segment in segments {
+        match segment {
+            Segment::Text(text) if trim_next => output.push_str(text.trim_start()),
+            Segment::Text(text) => output.push_str(text),
+            Segment::Output {
+                expression,
+                trim_before,
+                trim_after,
+                ..
+            } if resolved(variables(expression)) => {
+                if trim_before {
+                    output.truncate(output.trim_end().len());
+                }
+                let text = render_expression(expression, runtime)?;
+                output.push_str(&escape_text(text, delimiters));
+                trim_next = trim_after;
+                continue;
+            }
+            Segment::Output { raw, .. } | Segment::Tag { raw, .. } => output.push_str(raw),
+        }
+        trim_next = false;
+    }
+    Ok(serde_json::Value::String(output))
+}
+
+fn render_expression(expression: &str, runtime: &dyn Runtime) -> Result<String, Error> {
+    let template = Template::new(liquid_core::parser::parse(
+        &format!("{{{{ {} }}}}", expression),
+        &OPTIONS,
+    )?);
+    Ok(template.render(&GlobalFrame::new(runtime))?)
+}
+
+/// Keeps rendered values from being read as markup by the next render.
+fn escape_markup(value: serde_json::Value, delimiters: &Delimiters) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(s) => serde_json::Value::String(escape_text(s, delimiters)),
+        serde_json::Value::Array(a) => serde_json::Value::Array(
+            a.into_iter()
+                .map(|v| escape_markup(v, delimiters))
+                .collect(),
+        ),
+        // An object that looks like a literal is wrapped in one so it isn't unwrapped.
+        serde_json::Value::Object(o) if literal(&o).is_some() => {
+            serde_json::json!({ "$literal": o })
+        }
+        serde_json::Value::Object(o) => serde_json::Value::Object(
+            o.into_iter()
+                .map(|(k, v)| (k, escape_markup(v, delimiters)))
+                .collect(),
+        ),
+        _ => value,
+    }
+}
+
+fn escape_text(text: String, delimiters: &Delimiters) -> String {
+    match delimiters.escape(&text) {
+        Cow::Borrowed(_) => text,
+        Cow::Owned(escaped) => escaped,
+    }
+}
+
+/// Splits a template string into text, expressions and tags marked by `delimiters`.
+pub(crate) fn segments<'s>(template: &'s str, delimiters: &Delimiters) -> Vec<Segment<'s>> {
+    let (output, tag) = (delimiters.output(), delimiters.tag());
+    let mut segments = Vec::new();
+    let mut rest = template;
+    while !rest.is_empty() {
+        let Some(start) = rest
+            .find(output.0)
+            .into_iter()
+            .chain(rest.find(tag.0))
+            .min()
+        else {
+            segments.push(Segment::Text(rest));
+            break;
+        };
+        if start > 0 {
+            segments.push(Segment::Text(&rest[..start]));
+            rest = &rest[start..];
+        }
+        // Check the longer start first in case one delimiter is a prefix of the other.
+        let is_tag = rest.starts_with(tag.0)
+            && (!rest.starts_with(output.0) || tag.0.len() > output.0.len());
+        let (open, close) = if is_tag { tag } else { output };
+        let Some(len) = markup_len(&rest[open.len()..], close) else {
+            // Leave unterminated markup for the final render to report.
+            segments.push(Segment::Text(rest));
+            break;
+        };
+        let end = open.len() + len + close.len();
+        let raw = &rest[..end];
+        let inner = &raw[open.len()..open.len() + len];
+        if !is_tag {
+            segments.push(Segment::Output {
+                raw,
+                expression: inner.trim_matches('-').trim(),
+                trim_before: inner.starts_with('-'),
+                trim_after: inner.ends_with('-'),
+            });
+            rest = &rest[end..];
+        } else if tag_name(inner.trim_matches('-')) == "raw" {
+            let (_, after) = delimiters.raw_content(&rest[end..]);
+            let raw_end = rest.len() - after.len();
+            segments.push(Segment::Text(&rest[..raw_end]));
+            rest = &rest[raw_end..];
+        } else {
+            segments.push(Segment::Tag {
+                raw,
+                body: inner.trim_matches('-').trim(),
+            });
+            rest = &rest[end..];
+        }
+    }
+    segments
+}
+
+fn tag_name(body: &str) -> &str {
+    body.split_whitespace().next().unwrap_or_default()
+}
+
+fn tag_arguments(body: &str) -> &str {
+    body.trim_start()[tag_name(body).len()..].trim_start()
+}
+
+/// Finds the variables an expression reads from the render's data, ignoring filter names,
+/// keyword argument names, properties and string literals.
+fn variables(expression: &str) -> Vec<&str> {
+    let mut variables = Vec::new();
+    let mut quote = None;
+    // The last character before the current word that isn't whitespace.
+    let mut previous = None;
+    let mut chars = expression.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+                previous = Some(c);
+            }
+            continue;
+        }
+        if c == '\'' || c == '"' {
+            quote = Some(c);
+            continue;
+        }
+        if !(c.is_alphabetic() || c == '_') {
+            if !c.is_whitespace() {
+                previous = Some(c);
+            }
+            continue;
+        }
+        let mut end = i + c.len_utf8();
+        while let Some((j, n)) = chars.peek() {
+            if n.is_alphanumeric() || *n == '_' || *n == '-' {
+                end = j + n.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        let word = &expression[i..end];
+        let next = expression[end..].trim_start().chars().next();
+        let is_variable =
+            !matches!(previous, Some('.' | '|')) && next != Some(':') && !KEYWORDS.contains(&word);
+        if is_variable && !variables.contains(&word) {
+            variables.push(word);
+        }
+        previous = word.chars().last();
+    }
+    variables
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use crate::{Delimiters, Environment, LiquidJson};
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use anyhow::Result;
+
+    #[rstest]
+    #[case(json!({"port": "{{ port }}", "user": "{{ user }}"}), json!({"port": 8080}), json!({"port": 8080, "user": "{{ user }}"}))]
+    #[case(json!("{{ host }}:{{ port }}"), json!({"host": "db"}), json!("db:{{ port }}"))]
+    #[case(json!("{{ host | upcase }}/{{ path | prepend: '/' }}"), json!({"host": "db"}), json!("DB/{{ path | prepend: '/' }}"))]
+    #[case(json!("{{ a | plus: b }}"), json!({"a": 1}), json!("{{ a | plus: b }}"))]
+    #[case(json!("{{ a | plus: b }}"), json!({"a": 1, "b": 2}), json!("3"))]
+    #[case(json!("{{ items | each: '{{ el }}' | output }}"), json!({"items": [1, 2]}), json!([1, 2]))]
+    #[case(json!("{{ 'now' | to_iso8601 }} by {{ user }}"), json!({"user": "jane"}), json!("{{ 'now' | to_iso8601 }} by jane"))]
+    #[case(json!("{{ name | default: 'x' }}"), json!({}), json!("{{ name | default: 'x' }}"))]
+    #[case(json!("{{ user.name }} {{ env }}"), json!({"user": {"name": "jane"}}), json!("jane {{ env }}"))]
+    #[case(json!("{{ a }} {{- b -}} {{ c }}"), json!({"b": "B"}), json!("{{ a }}B{{ c }}"))]
+    #[case(json!("{{ a }}-{{ b }}"), json!({"a": "{{ evil }}"}), json!("{{ '{{' }} evil }}-{{ b }}"))]
+    #[case(json!("{% if debug %}{{ level }}{% endif %}"), json!({"debug": true}), json!("{% if debug %}{{ level }}{% endif %}"))]
+    #[case(json!("{% if debug %}{{ level }}{% endif %}"), json!({"debug": true, "level": "trace"}), json!("trace"))]
+    #[case(json!("{% raw %}{{ literal }}{% endraw %} {{ a }}"), json!({"a": 1}), json!("{% raw %}{{ literal }}{% endraw %} 1"))]
+    #[case(json!(["{{ a }}", 1, null, {"b": "{{ b }}"}]), json!({"b": false}), json!(["{{ a }}", 1, null, {"b": false}]))]
+    #[case(json!("{{ a }}"), json!({"a": "{% if %}"}), json!("{{ '{%' }} if %}"))]
+    #[case(json!("{{ a | output }}"), json!({"a": ["{{ b }}", 1]}), json!(["{{ '{{' }} b }}", 1]))]
+    #[case(json!({"a": "{{ a }}", "b": {"$literal": "{{ b }}"}}), json!({"a": 1, "b": 2}), json!({"a": 1, "b": {"$literal": "{{ b }}"}}))]
+    #[case(json!("{{ a }}"), json!({"a": {"$raw": "{{ b }}"}}), json!({"$literal": {"$raw": "{{ b }}"}}))]
+    fn partial(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render_partial(&data)?;
+        assert_eq!(actual.as_json(), &expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!({"url": "https://{{ host }}/{{ tenant }}/{{ id | plus: 1 }}", "port": "{{ port }}"}))]
+    #[case(json!("{{ host }}{{ id }}"))]
+    #[case(json!({"host": "{{ host }}", "hosts": "{{ host | split: ',' | output }}"}))]
+    #[case(json!(["{% if id > 1 %}{{ tenant }}{% else %}none{% endif %}", "{{ host | append: '/' }}"]))]
+    #[case(json!({"docs": {"$literal": "{{ host }}"}, "host": "{{ host }}"}))]
+    #[case(json!("{{ note }}-{{ tenant }}"))]
+    #[case(json!({"note": "{{ note }}", "tenant": "{{ tenant }}"}))]
+    fn stages(#[case] template: Value) -> Result<()> {
+        let note = "x{% endraw %}{{ tenant }}{% raw %}";
+        let first = json!({"host": "{{ example.com }}", "port": 80, "note": note});
+        let second = json!({"tenant": "acme", "id": 41});
+        let all = json!({"host": "{{ example.com }}", "port": 80, "note": note, "tenant": "acme", "id": 41});
+
+        let tmpl = LiquidJson::new(template);
+        let staged = tmpl.render_partial(&first)?.render(&second)?;
+        assert_eq!(staged, tmpl.render(&all)?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(json!("[[ a ]] {{ b }} [[ c ]]"), json!({"a": 1, "b": 2}), json!("1 {{ b }} [[ c ]]"))]
+    #[case(json!("[[ a ]]"), json!({"a": 1}), json!(1))]
+    #[case(json!("[[ a ]]-[[ c ]]"), json!({"a": "x[% endraw %][[ c ]][% raw %]"}), json!("x[[ '[%' ]] endraw %][[ '[[' ]] c ]][[ '[%' ]] raw %]-[[ c ]]"))]
+    #[case(json!("[[ a ]]-[[ c ]]"), json!({"a": "[[ x ]] {{ y }}"}), json!("[[ '[[' ]] x ]] {{ y }}-[[ c ]]"))]
+    #[case(json!("[% if a %][[ c ]][% endif %]"), json!({"a": true}), json!("[% if a %][[ c ]][% endif %]"))]
+    #[case(json!("[% raw %][[ a ]][% endraw %] [[ a ]]"), json!({"a": 1}), json!("[% raw %][[ a ]][% endraw %] 1"))]
+    fn delimiters(
+        #[case] template: Value,
+        #[case] data: Value,
+        #[case] expected: Value,
+    ) -> Result<()> {
+        let env = Environment::new().with_delimiters(Delimiters::new(("[[", "]]"), ("[%", "%]")));
+        let tmpl = LiquidJson::new(template);
+        let actual = tmpl.render_partial_with(&data, &env)?;
+        assert_eq!(actual.as_json(), &expected);
+        // Everything left in place renders the same as rendering the template in one go.
+        let mut all = data.as_object().cloned().unwrap_or_default();
+        all.insert("c".to_owned(), json!(3));
+        let all = Value::Object(all);
+        assert_eq!(
+            actual.render_with(&all, &env)?,
+            tmpl.render_with(&all, &env)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn environment() -> Result<()> {
+        let env = Environment::new()
+            .with_seed(7)
+            .with_now(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        let tmpl = LiquidJson::new(
+            json!({"roll": "{{ min | random_int: 1000 }}", "at": "{{ at | to_unix }}"}),
+        );
+        let data = json!({"min": 1, "at": "now"});
+        let staged = tmpl.render_partial_with(&data, &env)?;
+        assert_eq!(staged.as_json(), &tmpl.render_with(&data, &env)?);
+        assert_eq!(staged.as_json()["at"], json!("1700000000"));
+        Ok(())
+    }
+}
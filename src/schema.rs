@@ -0,0 +1,176 @@
+use std::borrow::Cow;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+use serde_json::{json, Map, Value};
+
+use crate::LiquidJsonValue;
+
+/// Prefix for the definitions of recursive types whose values may be templates.
+const DEFINITION_PREFIX: &str = "Liquid";
+
+/// The schema of a string that contains Liquid markup.
+fn template_schema() -> Value {
+    json!({
+        "type": "string",
+        "pattern": r"\{\{|\{%",
+        "description": "A Liquid template."
+    })
+}
+
+/// The schema of a [LiquidJsonValue] is the schema of the type it renders into, except that any
+/// value may be replaced by a Liquid template string. Templates are recognized by the standard
+/// `{{ }}` and `{% %}` delimiters.
+impl<T: JsonSchema> JsonSchema for LiquidJsonValue<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("{}{}", DEFINITION_PREFIX, T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("liquid_json::LiquidJsonValue<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        // Generate the type's schema with everything inlined, so its subschemas can accept
+        // templates without changing the shared definitions other fields refer to. Only
+        // recursive types are left as definitions.
+        let mut settings = generator.settings().clone();
+        settings.inline_subschemas = true;
+        let mut inline = settings.into_generator();
+        let mut schema = inline.subschema_for::<T>().to_value();
+        allow_templates(&mut schema);
+
+        for (name, mut definition) in inline.take_definitions(false) {
+            allow_templates(&mut definition);
+            generator
+                .definitions_mut()
+                .insert(format!("{}{}", DEFINITION_PREFIX, name), definition);
+        }
+        Schema::try_from(schema).unwrap_or_default()
+    }
+}
+
+/// Rewrites a schema so every value it describes may also be a template string.
+fn allow_templates(schema: &mut Value) {
+    let Value::Object(object) = schema else {
+        return;
+    };
+
+    for key in ["properties", "patternProperties"] {
+        if let Some(Value::Object(properties)) = object.get_mut(key) {
+            properties.values_mut().for_each(allow_templates);
+        }
+    }
+    for key in ["items", "additionalProperties"] {
+        if let Some(subschema) = object.get_mut(key) {
+            allow_templates(subschema);
+        }
+    }
+    // A template matches every alternative once they accept templates, so `oneOf` can only hold
+    // as `anyOf`.
+    if let Some(alternatives) = object.remove("oneOf") {
+        object.insert("anyOf".to_owned(), alternatives);
+    }
+    for key in ["prefixItems", "anyOf", "allOf"] {
+        if let Some(Value::Array(subschemas)) = object.get_mut(key) {
+            subschemas.iter_mut().for_each(allow_templates);
+        }
+    }
+    if let Some(Value::String(reference)) = object.get_mut("$ref") {
+        if let Some(i) = reference.rfind('/') {
+            reference.insert_str(i + 1, DEFINITION_PREFIX);
+        }
+    }
+
+    let constrained = ["type", "enum", "const", "$ref"]
+        .iter()
+        .any(|key| object.contains_key(*key));
+    let any_string = object.len() == 1 && object.get("type") == Some(&json!("string"));
+    if constrained && !any_string {
+        let original = Value::Object(std::mem::take(object));
+        *object = Map::from_iter([(
+            "anyOf".to_owned(),
+            Value::Array(vec![original, template_schema()]),
+        )]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::{schema_for, JsonSchema};
+    use serde::Deserialize;
+    use serde_json::{json, Value};
+
+    use crate::LiquidJsonValue;
+
+    #[derive(Deserialize, JsonSchema)]
+    #[allow(dead_code)]
+    struct Server {
+        host: String,
+        port: u16,
+        mode: Mode,
+    }
+
+    #[derive(Deserialize, JsonSchema)]
+    #[serde(rename_all = "lowercase")]
+    #[allow(dead_code)]
+    enum Mode {
+        Primary,
+        Replica,
+    }
+
+    #[derive(Deserialize, JsonSchema)]
+    #[allow(dead_code)]
+    struct Tree {
+        name: String,
+        children: Vec<Tree>,
+    }
+
+    #[derive(Deserialize, JsonSchema)]
+    #[allow(dead_code)]
+    struct Config {
+        server: LiquidJsonValue<Server>,
+        port: LiquidJsonValue<u16>,
+        tree: LiquidJsonValue<Tree>,
+        any: LiquidJsonValue,
+        plain: Server,
+    }
+
+    fn template_schema() -> Value {
+        super::template_schema()
+    }
+
+    #[test]
+    fn schema() {
+        let schema = schema_for!(Config).to_value();
+        let properties = &schema["properties"];
+
+        assert_eq!(
+            properties["port"],
+            json!({"anyOf": [{"type": "integer", "format": "uint16", "minimum": 0, "maximum": 65535}, template_schema()]})
+        );
+        assert_eq!(properties["any"], json!(true));
+
+        let server = &properties["server"]["anyOf"][0];
+        assert_eq!(server["type"], json!("object"));
+        assert_eq!(server["properties"]["host"], json!({"type": "string"}));
+        assert_eq!(server["properties"]["mode"]["anyOf"][1], template_schema());
+
+        // Fields that aren't templates keep referring to the shared definitions.
+        assert_eq!(properties["plain"], json!({"$ref": "#/$defs/Server"}));
+        assert_eq!(
+            schema["$defs"]["Server"]["properties"]["port"]["type"],
+            json!("integer")
+        );
+
+        // Recursive types get their own definitions that accept templates.
+        let children = &schema["$defs"]["LiquidTree"]["anyOf"][0]["properties"]["children"];
+        assert_eq!(
+            children["anyOf"][0]["items"],
+            json!({"anyOf": [{"$ref": "#/$defs/LiquidTree"}, template_schema()]})
+        );
+    }
+}